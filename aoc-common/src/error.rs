@@ -0,0 +1,253 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Why an input couldn't be parsed. Syntax errors carry enough context to
+// print a compiler-style diagnostic with a caret under the bad character.
+// Readers that check every line report all of their errors as Multiple.
+#[derive(Debug)]
+pub enum ParseError {
+    Io(io::Error),
+    Syntax {
+        file: Option<PathBuf>,
+        line: usize,   // 1-based
+        column: usize, // 1-based, in chars
+        expected: String,
+        text: String, // the offending line
+    },
+    Multiple(Vec<ParseError>),
+}
+
+impl ParseError {
+    pub fn at_column(line: usize, text: &str, column: usize, expected: &str) -> Self {
+        ParseError::Syntax {
+            file: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    // Error pointing at the start of token, which must be a slice of text
+    pub fn at_token(line: usize, text: &str, token: &str, expected: &str) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(text.as_ptr() as usize);
+        let offset = offset.min(text.len());
+        let column = text[..offset].chars().count() + 1;
+
+        Self::at_column(line, text, column, expected)
+    }
+
+    // Error pointing just past the end of text, for a missing token
+    pub fn at_end(line: usize, text: &str, expected: &str) -> Self {
+        Self::at_column(line, text, text.chars().count() + 1, expected)
+    }
+
+    // Record which file the error came from, if not already known
+    pub fn with_file(self, path: &Path) -> Self {
+        match self {
+            ParseError::Syntax {
+                file: None,
+                line,
+                column,
+                expected,
+                text,
+            } => ParseError::Syntax {
+                file: Some(path.to_path_buf()),
+                line,
+                column,
+                expected,
+                text,
+            },
+            ParseError::Io(err) => {
+                let message = format!("{}: {}", path.display(), err);
+                ParseError::Io(io::Error::new(err.kind(), message))
+            }
+            ParseError::Multiple(errors) => {
+                ParseError::Multiple(errors.into_iter().map(|e| e.with_file(path)).collect())
+            }
+            err => err,
+        }
+    }
+
+    // Record which file the error came from, replacing whatever name it was
+    // given before, e.g. when a copy of the file was parsed from memory
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            ParseError::Syntax {
+                line,
+                column,
+                expected,
+                text,
+                ..
+            } => ParseError::Syntax {
+                file: Some(path.to_path_buf()),
+                line,
+                column,
+                expected,
+                text,
+            },
+            ParseError::Multiple(errors) => {
+                ParseError::Multiple(errors.into_iter().map(|e| e.in_file(path)).collect())
+            }
+            err => err,
+        }
+    }
+
+    // Combine errors into one, or None if there weren't any
+    pub fn from_all(mut errors: Vec<ParseError>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(ParseError::Multiple(errors)),
+        }
+    }
+
+    // The individual errors, in the order they were found
+    pub fn into_errors(self) -> Vec<ParseError> {
+        match self {
+            ParseError::Multiple(errors) => {
+                errors.into_iter().flat_map(|e| e.into_errors()).collect()
+            }
+            err => vec![err],
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(err: io::Error) -> Self {
+        ParseError::Io(err)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Io(err) => write!(f, "error: {}", err),
+            ParseError::Syntax {
+                file,
+                line,
+                column,
+                expected,
+                text,
+            } => {
+                let file = match file {
+                    Some(path) => path.display().to_string(),
+                    None => "<input>".to_string(),
+                };
+                let gutter = " ".repeat(line.to_string().len());
+
+                writeln!(f, "error: expected {}", expected)?;
+                writeln!(f, "{}--> {}:{}:{}", gutter, file, line, column)?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", line, text)?;
+                write!(f, "{} | {}^", gutter, " ".repeat(column - 1))
+            }
+            ParseError::Multiple(errors) => {
+                for (i, err) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "\n\n")?;
+                    }
+                    write!(f, "{}", err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Why a part couldn't get an answer from an input that parsed fine, e.g. a
+// program that never halts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+// Why running a solution on an input failed: the input didn't parse, or a
+// part couldn't be solved from it
+#[derive(Debug)]
+pub enum RunError {
+    Parse(ParseError),
+    Solve { file: PathBuf, error: SolveError },
+}
+
+impl RunError {
+    // Name the input the error came from, replacing whatever name it was
+    // given before, e.g. when a copy of the file was parsed from memory
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            RunError::Parse(err) => RunError::Parse(err.in_file(path)),
+            RunError::Solve { error, .. } => RunError::Solve {
+                file: path.to_path_buf(),
+                error,
+            },
+        }
+    }
+}
+
+impl From<ParseError> for RunError {
+    fn from(err: ParseError) -> Self {
+        RunError::Parse(err)
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Parse(err) => write!(f, "{}", err),
+            RunError::Solve { file, error } => {
+                writeln!(f, "error: {}", error)?;
+                write!(f, " --> {}", file.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+// Parse token (a slice of text, the line it came from) or point at it
+pub fn parse_token<T: FromStr>(
+    line: usize,
+    text: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at_token(line, text, token, expected))
+}
+
+// Collect every item, or every error rather than just the first
+pub fn collect_all<T, C: FromIterator<T>>(
+    results: impl IntoIterator<Item = Result<T, ParseError>>,
+) -> Result<C, ParseError> {
+    let mut errors = vec![];
+    let items: Vec<T> = results
+        .into_iter()
+        .filter_map(|result| result.map_err(|err| errors.push(err)).ok())
+        .collect();
+
+    match ParseError::from_all(errors) {
+        Some(err) => Err(err),
+        None => Ok(items.into_iter().collect()),
+    }
+}
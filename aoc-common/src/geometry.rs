@@ -0,0 +1,216 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// A position (or an offset between positions) on a grid. Coordinates are
+// signed so stepping off the top or left edge gives a point that simply
+// isn't in the grid, rather than an underflow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    // One step in the given direction
+    pub fn offset(self, dir: impl Direction) -> Self {
+        self + dir.delta()
+    }
+
+    // n steps in the given direction
+    pub fn offset_by(self, dir: impl Direction, n: isize) -> Self {
+        self + dir.delta() * n
+    }
+
+    // Steps between two points moving only orthogonally
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    // The (row, col) index for this point, if neither coordinate is negative.
+    // Use Grid::contains to also check the far edges.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as isize, col as isize)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, n: isize) -> Point {
+        Point::new(self.row * n, self.col * n)
+    }
+}
+
+// Anything that can be stepped in, i.e. Dir4 or Dir8
+pub trait Direction: Copy {
+    // The offset of a single step
+    fn delta(self) -> Point;
+}
+
+// The four orthogonal directions, in clockwise order starting from up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    // Turn 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    // Turn 90 degrees anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+
+    // Arrows as used in the puzzle inputs: '^', '>', 'v' and '<'
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir4::Up),
+            '>' => Some(Dir4::Right),
+            'v' => Some(Dir4::Down),
+            '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+}
+
+impl Direction for Dir4 {
+    fn delta(self) -> Point {
+        match self {
+            Dir4::Up => Point::new(-1, 0),
+            Dir4::Right => Point::new(0, 1),
+            Dir4::Down => Point::new(1, 0),
+            Dir4::Left => Point::new(0, -1),
+        }
+    }
+}
+
+// The eight compass directions, in clockwise order starting from up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    // Turn 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    // Turn 45 degrees anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl Direction for Dir8 {
+    fn delta(self) -> Point {
+        match self {
+            Dir8::Up => Point::new(-1, 0),
+            Dir8::UpRight => Point::new(-1, 1),
+            Dir8::Right => Point::new(0, 1),
+            Dir8::DownRight => Point::new(1, 1),
+            Dir8::Down => Point::new(1, 0),
+            Dir8::DownLeft => Point::new(1, -1),
+            Dir8::Left => Point::new(0, -1),
+            Dir8::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
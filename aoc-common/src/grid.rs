@@ -0,0 +1,233 @@
+use crate::error::ParseError;
+use crate::geometry::{Dir4, Dir8, Point};
+use std::io::BufRead;
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+
+// A rectangular grid of cells, indexed by (row, col). Derefs to the underlying
+// grid::Grid so rows(), cols(), iter() etc. are all still available.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T>(::grid::Grid<T>);
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize) -> Self
+    where
+        T: Default,
+    {
+        Grid(::grid::Grid::new(rows, cols))
+    }
+
+    // Build a grid from a list of rows, which must all be the same length.
+    // Errors point at the first row that's too long or too short; since the
+    // rows are already parsed the offending line's text isn't available.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(ParseError::at_column(1, "", 1, "a grid row"));
+        }
+
+        let max_cols = rows[0].len();
+        for (row, cells) in rows.iter().enumerate() {
+            if cells.len() != max_cols {
+                return Err(ragged_row(row, cells.len(), max_cols, ""));
+            }
+        }
+
+        let flat_vec: Vec<T> = rows.into_iter().flatten().collect();
+        Ok(Grid(::grid::Grid::from_vec(flat_vec, max_cols)))
+    }
+
+    // Find the first cell (in row-major order) holding a marker like 'S' or '^'
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.0
+            .indexed_iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all(&self, value: &T) -> Vec<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.0
+            .indexed_iter()
+            .filter(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    pub fn into_inner(self) -> ::grid::Grid<T> {
+        self.0
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.to_index()
+            .is_some_and(|(row, col)| row < self.rows() && col < self.cols())
+    }
+
+    // The cell at p, or None if p is off the grid
+    pub fn at(&self, p: Point) -> Option<&T> {
+        let (row, col) = p.to_index()?;
+        self.0.get(row, col)
+    }
+
+    pub fn at_mut(&mut self, p: Point) -> Option<&mut T> {
+        let (row, col) = p.to_index()?;
+        self.0.get_mut(row, col)
+    }
+
+    // Every position in the grid, in row-major order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols();
+        (0..self.rows() * cols).map(move |i| Point::from((i / cols, i % cols)))
+    }
+
+    // The up/right/down/left neighbours of p that are on the grid
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .map(move |dir| p.offset(dir))
+            .filter(|&n| self.contains(n))
+    }
+
+    // As neighbors4, but including diagonals
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .map(move |dir| p.offset(dir))
+            .filter(|&n| self.contains(n))
+    }
+}
+
+impl Grid<char> {
+    // Find a marker that must appear exactly once, like the guard's '^'
+    pub fn expect_one(&self, marker: char, what: &str) -> Result<(usize, usize), ParseError> {
+        let row_text = |row: usize| self.iter_row(row).collect::<String>();
+
+        match self.find_all(&marker).as_slice() {
+            [pos] => Ok(*pos),
+            [] => {
+                let expected = format!("'{}' marking {}", marker, what);
+                Err(ParseError::at_column(self.rows() + 1, "", 1, &expected))
+            }
+            [_, rest @ ..] => {
+                let expected = format!("only one '{}' marking {}", marker, what);
+                let errors = rest
+                    .iter()
+                    .map(|&(row, col)| {
+                        ParseError::at_column(row + 1, &row_text(row), col + 1, &expected)
+                    })
+                    .collect();
+                Err(ParseError::Multiple(errors))
+            }
+        }
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = ::grid::Grid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        &self.0[pos]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        &mut self.0[pos]
+    }
+}
+
+// Indexing by Point panics off the grid, like indexing by (row, col)
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.at(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.at_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", p))
+    }
+}
+
+fn ragged_row(row: usize, len: usize, max_cols: usize, text: &str) -> ParseError {
+    if len < max_cols {
+        let expected = format!("{} columns, found {}", max_cols, len);
+        ParseError::at_column(row + 1, text, len + 1, &expected)
+    } else {
+        let expected = format!("end of row after {} columns", max_cols);
+        ParseError::at_column(row + 1, text, max_cols + 1, &expected)
+    }
+}
+
+fn parse_cells<T, S: AsRef<str>>(
+    lines: &[S],
+    expected: &str,
+    parse_cell: impl Fn(char) -> Option<T>,
+) -> Result<Grid<T>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    let mut errors = vec![];
+
+    for (row, line) in lines.iter().enumerate() {
+        let text = line.as_ref();
+        let mut cells = vec![];
+        for (col, c) in text.chars().enumerate() {
+            match parse_cell(c) {
+                Some(cell) => cells.push(cell),
+                None => errors.push(ParseError::at_column(row + 1, text, col + 1, expected)),
+            }
+        }
+
+        let width = lines[0].as_ref().chars().count();
+        let len = text.chars().count();
+        if len != width {
+            errors.push(ragged_row(row, len, width, text));
+        }
+        rows.push(cells);
+    }
+
+    if let Some(err) = ParseError::from_all(errors) {
+        return Err(err);
+    }
+    Grid::from_rows(rows)
+}
+
+fn read_lines(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    Ok(reader.lines().collect::<Result<_, _>>()?)
+}
+
+// Parse already-read lines into a grid of chars or any type convertible from a char
+pub fn parse_grid<T: TryFrom<char>, S: AsRef<str>>(lines: &[S]) -> Result<Grid<T>, ParseError> {
+    parse_cells(lines, "a grid cell", |c| T::try_from(c).ok())
+}
+
+pub fn read_grid<T: TryFrom<char>>(reader: impl BufRead) -> Result<Grid<T>, ParseError> {
+    parse_grid(&read_lines(reader)?)
+}
+
+// Read a grid of single digits, e.g. a height map
+pub fn read_digit_grid(reader: impl BufRead) -> Result<Grid<u8>, ParseError> {
+    parse_cells(&read_lines(reader)?, "a digit", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })
+}
@@ -0,0 +1,71 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::{Path, PathBuf};
+
+// Where a puzzle input comes from: a file, stdin (`-` on the command line) or
+// a string given inline with --input-str
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Str(String),
+}
+
+impl InputSource {
+    // `-` means stdin, anything else is a file path
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    // Take the input from the front of args, either `--input-str <text>` or a
+    // single path/`-`, and return it along with the remaining args
+    pub fn from_args(args: &[String]) -> Option<(Self, &[String])> {
+        match args {
+            [flag, text, rest @ ..] if flag == "--input-str" => {
+                Some((InputSource::Str(text.clone()), rest))
+            }
+            [flag, ..] if flag == "--input-str" => None,
+            [arg, rest @ ..] => Some((Self::from_arg(arg), rest)),
+            [] => None,
+        }
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead + '_>, ParseError> {
+        Ok(match self {
+            InputSource::File(path) => Box::new(BufReader::new(File::open(path)?)),
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::Str(text) => Box::new(Cursor::new(text.as_bytes())),
+        })
+    }
+
+    // Read the whole input, e.g. to parse it more than once
+    pub fn read_to_string(&self) -> Result<String, ParseError> {
+        let mut text = String::new();
+        self.reader()
+            .and_then(|mut reader| Ok(reader.read_to_string(&mut text)?))
+            .map_err(|err| err.with_file(self.path()))?;
+
+        Ok(text)
+    }
+
+    // What to call the input in diagnostics
+    pub fn path(&self) -> &Path {
+        match self {
+            InputSource::File(path) => path,
+            InputSource::Stdin => Path::new("<stdin>"),
+            InputSource::Str(_) => Path::new("<input-str>"),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path().display())
+    }
+}
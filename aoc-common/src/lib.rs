@@ -0,0 +1,17 @@
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod search;
+pub mod solution;
+
+pub use self::error::{collect_all, parse_token, ParseError, RunError, SolveError};
+pub use self::geometry::{Dir4, Dir8, Direction, Point};
+pub use self::grid::{parse_grid, read_digit_grid, read_grid, Grid};
+pub use self::input::InputSource;
+pub use self::output::{print_answer, to_json, warn, Format};
+pub use self::search::{astar, dijkstra, ShortestPaths};
+pub use self::solution::{
+    run_main, run_main_with, solve, solve_timed, solve_timed_with, validate, Solution, Timed,
+};
@@ -0,0 +1,96 @@
+use crate::solution::Timed;
+use std::cell::RefCell;
+use std::fmt::Write;
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+// Note something suspicious about the input or answer without failing, e.g.
+// more than one candidate answer. Warnings end up in the answer's output.
+pub fn warn(message: impl Into<String>) {
+    WARNINGS.with(|warnings| warnings.borrow_mut().push(message.into()));
+}
+
+// Collect (and clear) the warnings raised so far on this thread
+pub(crate) fn take_warnings() -> Vec<String> {
+    WARNINGS.with(|warnings| warnings.take())
+}
+
+// How answers are printed: the bare answer, or one JSON object per line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    // Remove `--format <text|json>` from args. None if the format is unknown.
+    pub fn take_from_args(args: &mut Vec<String>) -> Option<Format> {
+        let Some(idx) = args.iter().position(|arg| arg == "--format") else {
+            return Some(Format::Text);
+        };
+
+        let format = match args.get(idx + 1).map(|s| s.as_str()) {
+            Some("text") => Format::Text,
+            Some("json") => Format::Json,
+            _ => return None,
+        };
+        args.drain(idx..idx + 2);
+
+        Some(format)
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// {"day": .., "part": .., "answer": .., "elapsed_ms": .., "warnings": [..]}
+// The answer is always a string (some are multi-line), or null if the part
+// isn't implemented.
+pub fn to_json(day: u8, part: u8, timed: &Timed) -> String {
+    let answer = match &timed.answer {
+        Some(answer) => json_string(answer),
+        None => "null".to_string(),
+    };
+    let warnings: Vec<String> = timed.warnings.iter().map(|w| json_string(w)).collect();
+
+    format!(
+        "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"elapsed_ms\": {:.3}, \"warnings\": [{}]}}",
+        day,
+        part,
+        answer,
+        (timed.parse + timed.solve).as_secs_f64() * 1000.0,
+        warnings.join(", ")
+    )
+}
+
+// Print an answer in the given format. In text mode warnings go to stderr so
+// stdout only ever has the answer on it.
+pub fn print_answer(format: Format, day: u8, part: u8, timed: &Timed) {
+    match format {
+        Format::Json => println!("{}", to_json(day, part, timed)),
+        Format::Text => {
+            for warning in &timed.warnings {
+                eprintln!("warning: {}", warning);
+            }
+            if let Some(answer) = &timed.answer {
+                println!("{}", answer);
+            }
+        }
+    }
+}
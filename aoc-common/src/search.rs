@@ -0,0 +1,124 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+// The result of a shortest-path search: the optimal cost, one path achieving
+// it (start to goal, inclusive), and every state that lies on some optimal path
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    pub cost: usize,
+    pub path: Vec<S>,
+    pub best_states: HashSet<S>,
+}
+
+// Dijkstra's algorithm from start until a state satisfying is_goal is reached.
+// successors gives each neighbouring state along with the cost of moving there.
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+// A* search, as dijkstra but guided by a heuristic. The heuristic must be
+// consistent (never drop by more than the cost of a move, and 0 at a goal)
+// or the results may not be optimal.
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<ShortestPaths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    // States are numbered as they're discovered so the heap and predecessor
+    // lists don't need S to be Ord or cheap to clone
+    let mut states = vec![start.clone()];
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let mut dist = vec![0];
+    let mut preds: Vec<Vec<usize>> = vec![vec![]];
+
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut best = None;
+    let mut goals = vec![];
+
+    while let Some(Reverse((estimate, cost, id))) = heap.pop() {
+        // Everything left is worse than a goal we've already reached
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        // Stale entry, a cheaper way here was found since it was pushed
+        if cost > dist[id] {
+            continue;
+        }
+
+        if is_goal(&states[id]) {
+            best = Some(cost);
+            goals.push(id);
+            continue;
+        }
+
+        for (next, step) in successors(&states[id].clone()) {
+            let next_cost = cost + step;
+            let next_id = match ids.get(&next) {
+                Some(&next_id) => next_id,
+                None => {
+                    ids.insert(next.clone(), states.len());
+                    states.push(next);
+                    dist.push(usize::MAX);
+                    preds.push(vec![]);
+                    states.len() - 1
+                }
+            };
+
+            if next_cost < dist[next_id] {
+                dist[next_id] = next_cost;
+                preds[next_id] = vec![id];
+                let estimate = next_cost + heuristic(&states[next_id]);
+                heap.push(Reverse((estimate, next_cost, next_id)));
+            } else if next_cost == dist[next_id] && next_id != 0 {
+                // Another optimal way in. Nothing leads back to the start
+                // (only a zero-cost cycle could), so paths end there.
+                preds[next_id].push(id);
+            }
+        }
+    }
+
+    let cost = best?;
+
+    // Follow the first predecessor back from the first goal for one path.
+    // That's the one that set each state's cost, so was settled before it
+    // and the walk can't go round a zero-cost cycle.
+    let mut path = vec![goals[0]];
+    while let Some(&prev) = preds[*path.last().unwrap()].first() {
+        path.push(prev);
+    }
+
+    // Follow every predecessor back from every goal for all of them
+    let mut seen: HashSet<usize> = goals.iter().copied().collect();
+    let mut stack = goals;
+    while let Some(id) = stack.pop() {
+        for &prev in &preds[id] {
+            if seen.insert(prev) {
+                stack.push(prev);
+            }
+        }
+    }
+
+    Some(ShortestPaths {
+        cost,
+        path: path
+            .into_iter()
+            .rev()
+            .map(|id| states[id].clone())
+            .collect(),
+        best_states: seen.into_iter().map(|id| states[id].clone()).collect(),
+    })
+}
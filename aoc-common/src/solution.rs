@@ -0,0 +1,131 @@
+use crate::error::{ParseError, RunError, SolveError};
+use crate::input::InputSource;
+use crate::output::{print_answer, take_warnings, Format};
+use std::env;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+// A day's puzzle: parse the input once, then solve either part from it. Each
+// dayNN_P crate only implements the part it's named after; the other part
+// returns None. A part that can't get an answer from an input that parsed,
+// e.g. a program that never halts, returns a SolveError.
+pub trait Solution {
+    type Input;
+    const DAY: u8;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError>;
+
+    fn part1(_input: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(None)
+    }
+
+    fn part2(_input: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(None)
+    }
+}
+
+// An answer along with how long parsing and solving took, and any warnings
+// raised on the way
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Option<String>,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub warnings: Vec<String>,
+}
+
+// Parse the input and solve the given part, timing each step
+pub fn solve_timed<S: Solution>(part: u8, source: &InputSource) -> Result<Timed, RunError> {
+    solve_timed_with::<S>(part, source, |_| {})
+}
+
+// As solve_timed, but let the caller adjust the parsed input before solving,
+// e.g. to override a size that normally comes from a constant
+pub fn solve_timed_with<S: Solution>(
+    part: u8,
+    source: &InputSource,
+    adjust: impl FnOnce(&mut S::Input),
+) -> Result<Timed, RunError> {
+    take_warnings();
+
+    let start = Instant::now();
+    let mut input = source
+        .reader()
+        .and_then(S::parse)
+        .map_err(|err| err.with_file(source.path()))?;
+    let parse = start.elapsed();
+    adjust(&mut input);
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => S::part1(input),
+        2 => S::part2(input),
+        _ => Ok(None),
+    }
+    .map_err(|error| RunError::Solve {
+        file: source.path().to_path_buf(),
+        error,
+    })?;
+
+    Ok(Timed {
+        answer,
+        parse,
+        solve: start.elapsed(),
+        warnings: take_warnings(),
+    })
+}
+
+// Check that the input parses, without solving anything
+pub fn validate<S: Solution>(source: &InputSource) -> Result<(), ParseError> {
+    source
+        .reader()
+        .and_then(S::parse)
+        .map(|_| ())
+        .map_err(|err| err.with_file(source.path()))
+}
+
+// Parse the input and solve the given part
+pub fn solve<S: Solution>(part: u8, source: &InputSource) -> Result<Option<String>, RunError> {
+    solve_timed::<S>(part, source).map(|timed| timed.answer)
+}
+
+// Shared main() for the dayNN_P binaries
+pub fn run_main<S: Solution>(part: u8) {
+    run_main_with::<S, _>(part, "", |rest| {
+        rest.is_empty().then_some(|_: &mut S::Input| {})
+    })
+}
+
+// As run_main, for binaries taking more arguments after the input, shown as
+// extra_usage in the usage message. extra checks them before anything is
+// solved, returning None to print usage, or how to adjust the parsed input.
+pub fn run_main_with<S: Solution, F: FnOnce(&mut S::Input)>(
+    part: u8,
+    extra_usage: &str,
+    extra: impl FnOnce(&[String]) -> Option<F>,
+) {
+    let mut args: Vec<String> = env::args().collect();
+
+    let format = Format::take_from_args(&mut args);
+    let parsed =
+        InputSource::from_args(&args[1..]).and_then(|(source, rest)| Some((source, extra(rest)?)));
+    let (Some(format), Some((source, adjust))) = (format, parsed) else {
+        eprintln!(
+            "Usage: {} <file_path | - | --input-str <input>>{} [--format text|json]",
+            args[0], extra_usage
+        );
+        std::process::exit(1);
+    };
+
+    match solve_timed_with::<S>(part, &source, adjust) {
+        Ok(timed) if timed.answer.is_none() => {
+            eprintln!("Part {} is not implemented", part);
+            std::process::exit(1);
+        }
+        Ok(timed) => print_answer(format, S::DAY, part, &timed),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// Points, directions and grid neighbours at the edges
+use aoc_common::{Dir4, Dir8, Grid, Point};
+
+#[test]
+fn turns_go_round_the_compass() {
+    assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+    assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+    for dir in Dir4::ALL {
+        assert_eq!(dir.turn_right().turn_left(), dir);
+        assert_eq!(Dir4::from_char(dir.to_char()), Some(dir));
+    }
+
+    assert_eq!(Dir8::Up.turn_right(), Dir8::UpRight);
+    assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+    assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+}
+
+#[test]
+fn offsets_step_in_a_direction() {
+    let p = Point::new(2, 3);
+    assert_eq!(p.offset(Dir4::Up), Point::new(1, 3));
+    assert_eq!(p.offset_by(Dir8::DownLeft, 2), Point::new(4, 1));
+    assert_eq!(Point::new(0, 0).offset(Dir4::Left).to_index(), None);
+}
+
+#[test]
+fn neighbours_stay_on_the_grid() {
+    let grid: Grid<u8> = Grid::new(3, 4);
+
+    let corner: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+    assert_eq!(corner, vec![Point::new(0, 1), Point::new(1, 0)]);
+    assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+    assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    assert_eq!(grid.neighbors4(Point::new(2, 3)).count(), 2);
+
+    assert!(grid.at(Point::new(3, 0)).is_none());
+    assert!(!grid.contains(Point::new(0, -1)));
+    assert_eq!(grid.points().count(), 12);
+}
@@ -0,0 +1,31 @@
+use aoc_common::{to_json, Format, Timed};
+use std::time::Duration;
+
+#[test]
+fn json_output_escapes_multiline_answers() {
+    let timed = Timed {
+        answer: Some("#.\n.\"#".to_string()),
+        parse: Duration::from_millis(1),
+        solve: Duration::from_millis(2),
+        warnings: vec!["more than one answer".to_string()],
+    };
+
+    assert_eq!(
+        to_json(14, 2, &timed),
+        r##"{"day": 14, "part": 2, "answer": "#.\n.\"#", "elapsed_ms": 3.000, "warnings": ["more than one answer"]}"##
+    );
+}
+
+#[test]
+fn format_is_taken_out_of_the_args() {
+    let mut args: Vec<String> = ["aoc", "run", "--format", "json", "1", "1", "x.txt"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    assert_eq!(Format::take_from_args(&mut args), Some(Format::Json));
+    assert_eq!(args, ["aoc", "run", "1", "1", "x.txt"]);
+
+    let mut args = vec!["--format".to_string(), "xml".to_string()];
+    assert_eq!(Format::take_from_args(&mut args), None);
+}
@@ -0,0 +1,76 @@
+// Shortest paths over small hand-built graphs
+use aoc_common::{astar, dijkstra, Dir4, Direction, Point};
+use std::collections::HashSet;
+
+// a -> b -> d and a -> c -> d both cost 3, a -> d directly costs 5
+fn edges(state: &char) -> Vec<(char, usize)> {
+    match state {
+        'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+        'b' => vec![('d', 2)],
+        'c' => vec![('d', 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn finds_every_optimal_path() {
+    let best = dijkstra('a', edges, |&s| s == 'd').unwrap();
+
+    assert_eq!(best.cost, 3);
+    assert_eq!(best.path.first(), Some(&'a'));
+    assert_eq!(best.path.last(), Some(&'d'));
+    assert_eq!(best.best_states, HashSet::from(['a', 'b', 'c', 'd']));
+}
+
+#[test]
+fn unreachable_goal_gives_none() {
+    assert!(dijkstra('b', edges, |&s| s == 'a').is_none());
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    // An open 5x5 room, moving orthogonally
+    let end = Point::new(4, 4);
+    let successors = |p: &Point| {
+        Dir4::ALL
+            .into_iter()
+            .map(|dir| (*p + dir.delta(), 1))
+            .filter(|(n, _)| (0..5).contains(&n.row) && (0..5).contains(&n.col))
+            .collect::<Vec<_>>()
+    };
+
+    let plain = dijkstra(Point::new(0, 0), successors, |&p| p == end).unwrap();
+    let guided = astar(
+        Point::new(0, 0),
+        successors,
+        |p| p.manhattan(end),
+        |&p| p == end,
+    )
+    .unwrap();
+
+    assert_eq!(plain.cost, 8);
+    assert_eq!(guided.cost, 8);
+    assert_eq!(guided.path.len(), 9);
+    assert_eq!(plain.best_states, guided.best_states);
+    assert_eq!(guided.best_states.len(), 25);
+}
+
+#[test]
+fn zero_cost_cycles_end_at_the_start() {
+    // 0 and 1 lead to each other for free, as do 2 and 3
+    let successors = |&s: &u32| match s {
+        0 => vec![(1, 0)],
+        1 => vec![(0, 0), (2, 1)],
+        2 => vec![(3, 0)],
+        3 => vec![(2, 0), (4, 1)],
+        _ => vec![],
+    };
+
+    let best = dijkstra(0, successors, |&s| s == 4).unwrap();
+    assert_eq!(best.cost, 2);
+    assert_eq!(best.path, vec![0, 1, 2, 3, 4]);
+    assert_eq!(best.best_states, HashSet::from([0, 1, 2, 3, 4]));
+
+    let best = dijkstra(0, successors, |&s| s == 2).unwrap();
+    assert_eq!(best.path, vec![0, 1, 2]);
+}
@@ -0,0 +1,21 @@
+// Helpers for tests only, kept out of the libraries the solutions use.
+//
+// A small seeded generator for tests that try many random cases: the same
+// seed always gives the same numbers, so a failure can be reproduced.
+#[derive(Clone, Debug)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    // A number below n
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+}
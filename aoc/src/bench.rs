@@ -0,0 +1,98 @@
+use crate::solutions::Runner;
+use crate::synthetic::scale_input;
+use aoc_common::{InputSource, RunError};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Best-of-N timings for one day/part on one input
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub scale: usize,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+// Run a solution runs times and keep the fastest parse and solve. Taking the
+// minimum rather than the mean keeps the report stable enough to diff.
+fn time_runs(
+    runner: Runner,
+    input: &InputSource,
+    runs: usize,
+) -> Result<(Duration, Duration), RunError> {
+    let mut parse = Duration::MAX;
+    let mut solve = Duration::MAX;
+
+    for _ in 0..runs.max(1) {
+        let timed = runner(input)?;
+        parse = parse.min(timed.parse);
+        solve = solve.min(timed.solve);
+    }
+
+    Ok((parse, solve))
+}
+
+// Benchmark day/part on input, or on a synthetic copy scaled up by scale if
+// it's more than 1. Returns None if the day has no synthetic input. The input
+// is read once up front, so it can come from stdin.
+pub fn bench(
+    day: u8,
+    part: u8,
+    runner: Runner,
+    input: &InputSource,
+    scale: usize,
+    runs: usize,
+) -> Result<Option<Sample>, RunError> {
+    let mut text = input.read_to_string()?;
+    if scale > 1 {
+        let Some(scaled) = scale_input(day, &text, scale) else {
+            return Ok(None);
+        };
+        text = scaled;
+    }
+
+    // The runner names the in-memory copy, so name the real input instead,
+    // saying so if the lines are from a scaled copy of it
+    let name = if scale > 1 {
+        PathBuf::from(format!("{} (scaled x{})", input.path().display(), scale))
+    } else {
+        input.path().to_path_buf()
+    };
+    let (parse, solve) =
+        time_runs(runner, &InputSource::Str(text), runs).map_err(|err| err.in_file(&name))?;
+
+    Ok(Some(Sample {
+        day,
+        part,
+        input: input.path().to_path_buf(),
+        scale,
+        parse,
+        solve,
+    }))
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+// Tab-separated, one line per day/part, so reports from two commits diff cleanly
+pub fn write_report(samples: &[Sample], out: &mut impl Write) -> std::io::Result<()> {
+    writeln!(out, "day\tpart\tinput\tscale\tparse_ms\tsolve_ms")?;
+    for s in samples {
+        writeln!(
+            out,
+            "{}\t{}\t{}\t{}\t{:.3}\t{:.3}",
+            s.day,
+            s.part,
+            s.input.display(),
+            s.scale,
+            millis(s.parse),
+            millis(s.solve)
+        )?;
+    }
+
+    Ok(())
+}
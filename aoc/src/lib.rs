@@ -0,0 +1,3 @@
+pub mod bench;
+pub mod solutions;
+pub mod synthetic;
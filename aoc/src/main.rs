@@ -0,0 +1,251 @@
+use aoc::bench::{self, Sample};
+use aoc::solutions;
+use aoc_common::{print_answer, to_json, Format, InputSource};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} run <day> <part> <input> [--format text|json]",
+        program
+    );
+    eprintln!(
+        "       {} run --all <input_dir> [--format text|json]",
+        program
+    );
+    eprintln!("       {} bench <day> <part> <input> [options]", program);
+    eprintln!("       {} bench --all <input_dir> [options]", program);
+    eprintln!("       {} validate <day> <input>", program);
+    eprintln!();
+    eprintln!("<input> is a file path, - for stdin, or --input-str <text>");
+    eprintln!();
+    eprintln!("Bench options:");
+    eprintln!("  --scale <n>      time a synthetic input n times the size of the real one");
+    eprintln!("  --runs <n>       keep the fastest of n runs (default 5)");
+    eprintln!("  --report <path>  write the report there instead of stdout");
+    std::process::exit(1);
+}
+
+struct BenchOptions {
+    scale: usize,
+    runs: usize,
+    report: Option<String>,
+}
+
+// Split bench arguments into positional arguments and options
+fn parse_bench_args(program: &str, args: &[String]) -> (Vec<String>, BenchOptions) {
+    let mut positional = vec![];
+    let mut options = BenchOptions {
+        scale: 1,
+        runs: 5,
+        report: None,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.as_str() {
+            "--scale" | "--runs" | "--report" => match args.next() {
+                Some(value) => value,
+                None => usage(program),
+            },
+            _ => {
+                positional.push(arg.clone());
+                continue;
+            }
+        };
+
+        match arg.as_str() {
+            "--scale" => options.scale = value.parse().unwrap_or_else(|_| usage(program)),
+            "--runs" => options.runs = value.parse().unwrap_or_else(|_| usage(program)),
+            _ => options.report = Some(value.clone()),
+        }
+    }
+
+    (positional, options)
+}
+
+fn run_one(day: u8, part: u8, source: &InputSource, format: Format) {
+    let Some(runner) = solutions::find(day, part) else {
+        eprintln!("No solution for day {} part {}", day, part);
+        std::process::exit(1);
+    };
+
+    match runner(source) {
+        Ok(timed) => print_answer(format, day, part, &timed),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+// Run every solution against <input_dir>/dayNN.txt, skipping days without input
+fn run_all(input_dir: &Path, format: Format) {
+    for (day, part, runner) in solutions::SOLUTIONS {
+        let file_path = input_dir.join(format!("day{:02}.txt", day));
+        if !file_path.exists() {
+            eprintln!(
+                "day {} part {}: no input at {}",
+                day,
+                part,
+                file_path.display()
+            );
+            continue;
+        }
+
+        let source = InputSource::File(file_path);
+        let timed = match runner(&source) {
+            Ok(timed) => timed,
+            Err(err) => {
+                eprintln!("day {} part {}:\n{}", day, part, err);
+                continue;
+            }
+        };
+
+        if format == Format::Json {
+            println!("{}", to_json(*day, *part, &timed));
+            continue;
+        }
+        for warning in &timed.warnings {
+            eprintln!("day {} part {}: warning: {}", day, part, warning);
+        }
+        if let Some(answer) = timed.answer {
+            println!("day {} part {}: {}", day, part, answer);
+        }
+    }
+}
+
+fn bench_one(day: u8, part: u8, source: &InputSource, options: &BenchOptions) -> Vec<Sample> {
+    let Some(runner) = solutions::find(day, part) else {
+        eprintln!("No solution for day {} part {}", day, part);
+        std::process::exit(1);
+    };
+
+    match bench::bench(day, part, runner, source, options.scale, options.runs) {
+        Ok(Some(sample)) => vec![sample],
+        Ok(None) => {
+            eprintln!("No synthetic input for day {}", day);
+            std::process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+}
+
+// Benchmark every solution against <input_dir>/dayNN.txt
+fn bench_all(input_dir: &Path, options: &BenchOptions) -> Vec<Sample> {
+    let mut samples = vec![];
+
+    for (day, part, runner) in solutions::SOLUTIONS {
+        let file_path = input_dir.join(format!("day{:02}.txt", day));
+        if !file_path.exists() {
+            eprintln!(
+                "day {} part {}: no input at {}",
+                day,
+                part,
+                file_path.display()
+            );
+            continue;
+        }
+
+        let source = InputSource::File(file_path);
+        match bench::bench(*day, *part, *runner, &source, options.scale, options.runs) {
+            Ok(Some(sample)) => samples.push(sample),
+            Ok(None) => eprintln!("day {} part {}: no synthetic input", day, part),
+            Err(err) => eprintln!("day {} part {}:\n{}", day, part, err),
+        }
+    }
+
+    samples
+}
+
+fn run_bench(program: &str, args: &[String]) {
+    let (positional, options) = parse_bench_args(program, args);
+
+    let samples = match positional.as_slice() {
+        [flag, input_dir] if flag == "--all" => bench_all(Path::new(input_dir), &options),
+        [day, part, input @ ..] => {
+            let (Ok(day), Ok(part), Some((source, []))) =
+                (day.parse(), part.parse(), InputSource::from_args(input))
+            else {
+                usage(program);
+            };
+            bench_one(day, part, &source, &options)
+        }
+        _ => usage(program),
+    };
+
+    let written = match &options.report {
+        Some(path) => {
+            File::create(path).and_then(|mut file| bench::write_report(&samples, &mut file))
+        }
+        None => bench::write_report(&samples, &mut io::stdout()),
+    };
+    if let Err(err) = written {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+// Check an input against the day's format, reporting every problem found
+fn run_validate(program: &str, args: &[String]) {
+    let [day, input @ ..] = args else {
+        usage(program);
+    };
+    let (Ok(day), Some((source, []))) = (day.parse(), InputSource::from_args(input)) else {
+        usage(program);
+    };
+    let Some(validator) = solutions::find_validator(day) else {
+        eprintln!("No solution for day {}", day);
+        std::process::exit(1);
+    };
+
+    match validator(&source) {
+        Ok(()) => println!("{}: ok", source),
+        Err(err) => {
+            let errors = err.into_errors();
+            for err in &errors {
+                eprintln!("{}\n", err);
+            }
+            let plural = if errors.len() == 1 { "" } else { "s" };
+            eprintln!("{}: {} error{}", source, errors.len(), plural);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().collect();
+
+    if args.len() < 2 {
+        usage(&args[0]);
+    }
+
+    match args[1].as_str() {
+        "run" => {}
+        "bench" => return run_bench(&args[0], &args[2..]),
+        "validate" => return run_validate(&args[0], &args[2..]),
+        _ => usage(&args[0]),
+    }
+
+    let Some(format) = Format::take_from_args(&mut args) else {
+        usage(&args[0]);
+    };
+
+    match &args[2..] {
+        [flag, input_dir] if flag == "--all" => run_all(Path::new(input_dir), format),
+        [day, part, input @ ..] => {
+            let (Ok(day), Ok(part), Some((source, []))) =
+                (day.parse(), part.parse(), InputSource::from_args(input))
+            else {
+                usage(&args[0]);
+            };
+            run_one(day, part, &source, format)
+        }
+        _ => usage(&args[0]),
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_common::{solve_timed, validate, InputSource, ParseError, RunError, Solution, Timed};
+
+pub type Runner = fn(&InputSource) -> Result<Timed, RunError>;
+pub type Validator = fn(&InputSource) -> Result<(), ParseError>;
+
+fn part1<S: Solution>(source: &InputSource) -> Result<Timed, RunError> {
+    solve_timed::<S>(1, source)
+}
+
+fn part2<S: Solution>(source: &InputSource) -> Result<Timed, RunError> {
+    solve_timed::<S>(2, source)
+}
+
+// Every (day, part) we have a solution for, in order
+pub const SOLUTIONS: &[(u8, u8, Runner)] = &[
+    (1, 1, part1::<day01_1::Day01>),
+    (1, 2, part2::<day01_2::Day01>),
+    (2, 1, part1::<day02_1::Day02>),
+    (2, 2, part2::<day02_2::Day02>),
+    (3, 1, part1::<day03_1::Day03>),
+    (3, 2, part2::<day03_2::Day03>),
+    (4, 1, part1::<day04_1::Day04>),
+    (4, 2, part2::<day04_2::Day04>),
+    (5, 1, part1::<day05_1::Day05>),
+    (5, 2, part2::<day05_2::Day05>),
+    (6, 1, part1::<day06_1::Day06>),
+    (6, 2, part2::<day06_2::Day06>),
+    (7, 1, part1::<day07_1::Day07>),
+    (7, 2, part2::<day07_2::Day07>),
+    (8, 1, part1::<day08_1::Day08>),
+    (8, 2, part2::<day08_2::Day08>),
+    (9, 1, part1::<day09_1::Day09>),
+    (9, 2, part2::<day09_2::Day09>),
+    (10, 1, part1::<day10_1::Day10>),
+    (10, 2, part2::<day10_2::Day10>),
+    (11, 1, part1::<day11_1::Day11>),
+    (11, 2, part2::<day11_2::Day11>),
+    (12, 1, part1::<day12_1::Day12>),
+    (12, 2, part2::<day12_2::Day12>),
+    (13, 1, part1::<day13_1::Day13>),
+    (13, 2, part2::<day13_2::Day13>),
+    (14, 1, part1::<day14_1::Day14>),
+    (14, 2, part2::<day14_2::Day14>),
+    (15, 1, part1::<day15_1::Day15>),
+    (15, 2, part2::<day15_2::Day15>),
+    (16, 1, part1::<day16_1::Day16>),
+    (16, 2, part2::<day16_2::Day16>),
+    (17, 1, part1::<day17_1::Day17>),
+    (17, 2, part2::<day17_2::Day17>),
+];
+
+pub fn find(day: u8, part: u8) -> Option<Runner> {
+    SOLUTIONS
+        .iter()
+        .find(|(d, p, _)| *d == day && *p == part)
+        .map(|(_, _, runner)| *runner)
+}
+
+// One parser per day: both parts read the same input format
+pub const VALIDATORS: &[(u8, Validator)] = &[
+    (1, validate::<day01_1::Day01>),
+    (2, validate::<day02_1::Day02>),
+    (3, validate::<day03_1::Day03>),
+    (4, validate::<day04_1::Day04>),
+    (5, validate::<day05_1::Day05>),
+    (6, validate::<day06_1::Day06>),
+    (7, validate::<day07_1::Day07>),
+    (8, validate::<day08_1::Day08>),
+    (9, validate::<day09_1::Day09>),
+    (10, validate::<day10_1::Day10>),
+    (11, validate::<day11_1::Day11>),
+    (12, validate::<day12_1::Day12>),
+    (13, validate::<day13_1::Day13>),
+    (14, validate::<day14_1::Day14>),
+    (15, validate::<day15_1::Day15>),
+    (16, validate::<day16_1::Day16>),
+    (17, validate::<day17_1::Day17>),
+];
+
+pub fn find_validator(day: u8) -> Option<Validator> {
+    VALIDATORS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, validator)| *validator)
+}
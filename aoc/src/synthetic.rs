@@ -0,0 +1,114 @@
+// Synthetic inputs for benchmarking: a real (or example) input scaled up by
+// some factor, keeping it valid for the day's parser and solution.
+use aoc_common::Dir4;
+
+// Scale a day's input by factor, or None if the day has no sensible way to grow
+pub fn scale_input(day: u8, text: &str, factor: usize) -> Option<String> {
+    let scaled = match day {
+        1 | 2 | 3 | 7 | 14 => repeat_lines(text, factor),
+        4 | 8 | 10 | 12 => tile_grid(text, factor, false, |_, _| true),
+        5 => repeat_updates(text, factor),
+        // Only the guard in the bottom copy, whichever way it faces, so the
+        // map still has just the one
+        6 => tile_grid(text, factor, false, |c, copy| {
+            Dir4::from_char(c).is_none() || copy == factor - 1
+        }),
+        9 => repeat_diskmap(text, factor),
+        11 => repeat_words(text, factor),
+        13 => repeat_blocks(text, factor),
+        15 => repeat_moves(text, factor),
+        // Start at the bottom, end at the top, with the copies' walls merged
+        16 => tile_grid(text, factor, true, |c, copy| match c {
+            'S' => copy == factor - 1,
+            'E' => copy == 0,
+            _ => true,
+        }),
+        _ => return None,
+    };
+
+    Some(scaled)
+}
+
+fn repeat_lines(text: &str, factor: usize) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut result: Vec<&str> = vec![];
+    for _ in 0..factor {
+        result.extend(&lines);
+    }
+
+    result.join("\n") + "\n"
+}
+
+fn repeat_words(text: &str, factor: usize) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut result: Vec<&str> = vec![];
+    for _ in 0..factor {
+        result.extend(&words);
+    }
+
+    result.join(" ") + "\n"
+}
+
+// Blank-line separated blocks, e.g. day 13's machines
+fn repeat_blocks(text: &str, factor: usize) -> String {
+    let blocks: Vec<&str> = text.trim_end().split("\n\n").collect();
+    let mut result: Vec<&str> = vec![];
+    for _ in 0..factor {
+        result.extend(&blocks);
+    }
+
+    result.join("\n\n") + "\n"
+}
+
+// Day 5: the rules stay as they are, the updates repeat
+fn repeat_updates(text: &str, factor: usize) -> String {
+    let (rules, updates) = text.split_once("\n\n").unwrap_or((text, ""));
+    format!("{}\n\n{}", rules, repeat_lines(updates, factor))
+}
+
+// Day 9: the map alternates file, free, file, ..., so an odd-length map needs
+// an empty free span before the next copy starts with a file
+fn repeat_diskmap(text: &str, factor: usize) -> String {
+    let diskmap = text.trim();
+    let separator = if diskmap.len() % 2 == 1 { "0" } else { "" };
+
+    vec![diskmap; factor].join(separator) + "\n"
+}
+
+// Day 15: same warehouse, the robot just makes more moves
+fn repeat_moves(text: &str, factor: usize) -> String {
+    let (warehouse, moves) = text.split_once("\n\n").unwrap_or((text, ""));
+    format!("{}\n\n{}", warehouse, repeat_lines(moves, factor))
+}
+
+// Stack factor copies of a grid vertically. With merge_borders the wall rows
+// between copies are dropped so they join into one connected grid. Cells for
+// which keep(cell, copy) is false become '.', so markers can stay unique.
+fn tile_grid(
+    text: &str,
+    factor: usize,
+    merge_borders: bool,
+    keep: impl Fn(char, usize) -> bool,
+) -> String {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut result = vec![];
+
+    for copy in 0..factor {
+        let first = if merge_borders && copy > 0 { 1 } else { 0 };
+        let last = if merge_borders && copy < factor - 1 {
+            lines.len().saturating_sub(1)
+        } else {
+            lines.len()
+        };
+
+        for line in &lines[first.min(last)..last] {
+            let row: String = line
+                .chars()
+                .map(|c| if keep(c, copy) { c } else { '.' })
+                .collect();
+            result.push(row);
+        }
+    }
+
+    result.join("\n") + "\n"
+}
@@ -0,0 +1,131 @@
+// Golden-answer tests: every examples/dayNN/<name>.txt is run through each part
+// that has a <name>.partN.expected file next to it, and the answer compared to
+// the file's contents. Adding an example is just dropping the files in. Day 14
+// examples can give their space's size in a <name>.size file, as "11 7".
+use aoc::solutions;
+use aoc_common::{solve_timed_with, InputSource, RunError, Timed};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples")
+}
+
+// Day number from a directory named dayNN
+fn day_of(dir: &Path) -> Option<u8> {
+    dir.file_name()?.to_str()?.strip_prefix("day")?.parse().ok()
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+// Width and height from a <name>.size file, if the example has one
+fn size_of(input: &Path) -> Option<Result<(i32, i32), String>> {
+    let text = fs::read_to_string(input.with_extension("size")).ok()?;
+    let size = match text.split_whitespace().collect::<Vec<_>>()[..] {
+        [width, height] => width.parse().ok().zip(height.parse().ok()),
+        _ => None,
+    };
+    Some(size.ok_or_else(|| {
+        format!(
+            "bad size {:?}, expected \"<width> <height>\"",
+            text.trim_end()
+        )
+    }))
+}
+
+// Run a part in a space of the given size, for the days that have one
+fn run_sized(
+    day: u8,
+    part: u8,
+    source: &InputSource,
+    (width, height): (i32, i32),
+) -> Option<Result<Timed, RunError>> {
+    match (day, part) {
+        (14, 1) => Some(solve_timed_with::<day14_1::Day14>(1, source, |input| {
+            (input.1, input.2) = (width, height)
+        })),
+        (14, 2) => Some(solve_timed_with::<day14_2::Day14>(2, source, |input| {
+            (input.1, input.2) = (width, height)
+        })),
+        _ => None,
+    }
+}
+
+#[test]
+fn examples_match_expected_answers() {
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for day_dir in sorted_entries(&examples_dir()) {
+        let Some(day) = day_of(&day_dir) else {
+            continue;
+        };
+
+        for input in sorted_entries(&day_dir) {
+            if input.extension().is_none_or(|ext| ext != "txt") {
+                continue;
+            }
+
+            for part in 1..=2 {
+                let expected_path = input.with_extension(format!("part{}.expected", part));
+                let Ok(expected) = fs::read_to_string(&expected_path) else {
+                    continue;
+                };
+
+                let name = format!("day {} part {} on {}", day, part, input.display());
+                let Some(runner) = solutions::find(day, part) else {
+                    failures.push(format!("{}: no solution registered", name));
+                    continue;
+                };
+
+                let source = InputSource::File(input.clone());
+                let result = match size_of(&input) {
+                    None => runner(&source),
+                    Some(Ok(size)) => match run_sized(day, part, &source, size) {
+                        Some(result) => result,
+                        None => {
+                            failures.push(format!("{}: has a size but day {} doesn't", name, day));
+                            continue;
+                        }
+                    },
+                    Some(Err(err)) => {
+                        failures.push(format!("{}: {}", name, err));
+                        continue;
+                    }
+                };
+
+                checked += 1;
+                match result.map(|timed| timed.answer) {
+                    Ok(Some(answer)) if answer.trim_end() == expected.trim_end() => {}
+                    Ok(Some(answer)) => failures.push(format!(
+                        "{}: expected {}, got {}",
+                        name,
+                        expected.trim_end(),
+                        answer.trim_end()
+                    )),
+                    Ok(None) => failures.push(format!("{}: part not implemented", name)),
+                    Err(err) => failures.push(format!("{}:\n{}", name, err)),
+                }
+            }
+        }
+    }
+
+    assert!(
+        checked > 0,
+        "no examples found in {}",
+        examples_dir().display()
+    );
+    assert!(
+        failures.is_empty(),
+        "{} failing examples:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,125 @@
+// Readers take any BufRead, so inputs can be built in memory
+use aoc::{bench, solutions};
+use aoc_common::{InputSource, RunError, Solution};
+use std::io::Cursor;
+use std::path::PathBuf;
+
+#[test]
+fn readers_parse_from_cursors() {
+    let (list1, list2) = day01_1::Day01::parse(Cursor::new("3   4\n4   3\n")).unwrap();
+    assert_eq!(list1, vec![3, 4]);
+    assert_eq!(list2, vec![4, 3]);
+
+    let diskmap = day09_1::Day09::parse(Cursor::new("12345\n")).unwrap();
+    assert_eq!(diskmap, vec![1, 2, 3, 4, 5]);
+
+    let grid = day10_1::Day10::parse(Cursor::new("01\n98\n")).unwrap();
+    assert_eq!(grid[(1, 0)], 9);
+}
+
+#[test]
+fn reader_errors_point_at_the_bad_token() {
+    let err = day11_1::Day11::parse(Cursor::new("125 x7\n")).unwrap_err();
+    let message = err.to_string();
+    assert!(message.contains("<input>:1:5"), "{}", message);
+}
+
+#[test]
+fn inline_input_matches_file_input() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../examples/day11/example.txt");
+    let text = std::fs::read_to_string(&path).unwrap();
+    let runner = solutions::find(11, 1).unwrap();
+
+    let from_file = runner(&InputSource::File(path)).unwrap().answer;
+    let from_str = runner(&InputSource::Str(text)).unwrap().answer;
+    assert_eq!(from_file, from_str);
+}
+
+#[test]
+fn bench_errors_name_the_real_input() {
+    let path = std::env::temp_dir().join(format!("aoc-bench-{}.txt", std::process::id()));
+    std::fs::write(&path, "3   4\n4 x\n").unwrap();
+    let runner = solutions::find(1, 1).unwrap();
+
+    let err = bench::bench(1, 1, runner, &InputSource::File(path.clone()), 1, 1).unwrap_err();
+    let message = err.to_string();
+    std::fs::remove_file(&path).unwrap();
+    let location = format!("{}:2:4", path.display());
+    assert!(message.contains(&location), "{}", message);
+}
+
+// Inputs that parse but that a part can't get an answer from
+#[test]
+fn unsolvable_inputs_are_errors() {
+    let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+    let program = |program: &str| format!("{}Program: {}\n", registers, program);
+    let looping = ".#..\n...#\n#^..\n..#.\n".to_string();
+    let cases = [
+        (17, 1, program("2,7"), "invalid combo operand 7"),
+        (17, 2, program("5,4"), "no value of A"),
+        (6, 1, looping, "never leaves the map"),
+        (16, 1, "#S#E#\n".to_string(), "no path from S to E"),
+        (16, 2, "#S#E#\n".to_string(), "no path from S to E"),
+    ];
+
+    for (day, part, input, expected) in cases {
+        let runner = solutions::find(day, part).unwrap();
+        let err = runner(&InputSource::Str(input)).unwrap_err();
+        assert!(matches!(err, RunError::Solve { .. }), "{:?}", err);
+        let message = err.to_string();
+        assert!(message.contains(expected), "{}", message);
+        assert!(message.contains("--> <input-str>"), "{}", message);
+    }
+}
+
+#[test]
+fn input_source_from_args() {
+    let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    let given = args(&["--input-str", "125 17", "11", "7"]);
+    let (source, rest) = InputSource::from_args(&given).unwrap();
+    assert!(matches!(source, InputSource::Str(ref s) if s == "125 17"));
+    assert_eq!(rest, &given[2..]);
+
+    let given = args(&["-"]);
+    assert!(matches!(
+        InputSource::from_args(&given),
+        Some((InputSource::Stdin, []))
+    ));
+
+    assert!(InputSource::from_args(&args(&["--input-str"])).is_none());
+    assert!(InputSource::from_args(&[]).is_none());
+}
+
+#[test]
+fn validation_reports_every_error() {
+    let validate = solutions::find_validator(5).unwrap();
+    let input = "47|53\n97x13\n\n75,47,61\n97,6a,53\n".to_string();
+
+    let errors = validate(&InputSource::Str(input)).unwrap_err().into_errors();
+    assert_eq!(errors.len(), 2);
+}
+
+// Whatever validates has to run, so an update without a middle page is
+// caught by the parser rather than the solution
+#[test]
+fn day05_updates_need_a_middle_page() {
+    let input = || InputSource::Str("47|53\n\n75,47\n".to_string());
+
+    let validate = solutions::find_validator(5).unwrap();
+    let message = validate(&input()).unwrap_err().to_string();
+    assert!(message.contains("an odd number of pages"), "{}", message);
+    for part in 1..=2 {
+        let err = solutions::find(5, part).unwrap()(&input()).unwrap_err();
+        assert!(matches!(err, RunError::Parse(_)), "{:?}", err);
+    }
+}
+
+#[test]
+fn trailing_blank_lines_are_valid() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../examples/day13/example.txt");
+    let text = std::fs::read_to_string(path).unwrap() + "\n\n";
+
+    let validate = solutions::find_validator(13).unwrap();
+    assert!(validate(&InputSource::Str(text)).is_ok());
+}
@@ -0,0 +1,50 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::io::BufRead;
+
+fn parse_pair(idx: usize, l: &str) -> Result<(i32, i32), ParseError> {
+    let ll = l.split("   ").collect::<Vec<&str>>();
+    if ll.len() < 2 {
+        return Err(ParseError::at_end(idx + 1, l, "two numbers separated by three spaces"));
+    }
+
+    Ok((
+        parse_token(idx + 1, l, ll[0], "a number")?,
+        parse_token(idx + 1, l, ll[1], "a number")?,
+    ))
+}
+
+fn read_lists(reader: impl BufRead) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let pairs: Vec<(i32, i32)> =
+        collect_all(lines.iter().enumerate().map(|(idx, l)| parse_pair(idx, l)))?;
+
+    Ok(pairs.into_iter().unzip())
+}
+
+fn list_distance(list1: &mut [i32], list2: &mut [i32]) -> i32 {
+    list1.sort();
+    list2.sort();
+
+    let mut distance = 0;
+
+    for (a, b) in list1.iter().zip(list2.iter()) {
+        distance += (a-b).abs();
+    }
+
+    distance
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    const DAY: u8 = 1;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_lists(reader)
+    }
+
+    fn part1((mut list1, mut list2): Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(list_distance(&mut list1, &mut list2).to_string()))
+    }
+}
@@ -0,0 +1,66 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::io::BufRead;
+
+fn read_reports(reader: impl BufRead) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut reports = vec![];
+
+    let mut errors = vec![];
+
+    for (idx, line) in reader.lines().enumerate() {
+        let l = line?;
+        let ll = (l.as_str()).split(" ").collect::<Vec<&str>>();
+
+        match collect_all(ll.iter().map(|x| parse_token(idx + 1, &l, x, "a number"))) {
+            Ok(lll) => reports.push(lll),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    match ParseError::from_all(errors) {
+        Some(err) => Err(err),
+        None => Ok(reports),
+    }
+}
+
+fn is_gradual(report: Vec<i32>) -> bool {
+    // ensure max delta is 3 and min delta is 1
+    // ensure all deltas have the same sign
+    let deltas: Vec<i32> = report.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let all_gradual = deltas.iter().all(|&x| x.abs() >= 1 && x.abs() <= 3);
+    let all_positive = deltas.iter().all(|&x| x >= 0);
+    let all_negative = deltas.iter().all(|&x| x <= 0);
+
+    /*    println!(
+        "{:?}, {:?}, {}, {}, {}",
+        report, deltas, all_gradual, all_positive, all_negative
+    );*/
+
+    all_gradual && (all_positive || all_negative)
+}
+
+fn count_safe_reports(reports: Vec<Vec<i32>>) -> usize {
+    let mut safe_reports = 0;
+    for report in reports {
+        if is_gradual(report) {
+            safe_reports += 1;
+        }
+    }
+
+    safe_reports
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    const DAY: u8 = 2;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_reports(reader)
+    }
+
+    fn part1(reports: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(count_safe_reports(reports).to_string()))
+    }
+}
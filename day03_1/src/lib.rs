@@ -0,0 +1,34 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use std::io::BufRead;
+use regex::Regex;
+
+fn read_instructions(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+    Ok(lines)
+}
+
+fn do_multiplies(instructions: String) -> i32 {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+    let mut results = 0;
+
+    for (_, [m1, m2]) in re.captures_iter(instructions.as_str()).map(|c| c.extract()) {
+        results += m1.parse::<i32>().unwrap()*m2.parse::<i32>().unwrap();
+    }
+
+    results
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    const DAY: u8 = 3;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_instructions(reader)
+    }
+
+    fn part1(instructions: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(do_multiplies(instructions.join("")).to_string()))
+    }
+}
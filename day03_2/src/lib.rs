@@ -0,0 +1,48 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use std::io::BufRead;
+use regex::Regex;
+
+fn read_instructions(reader: impl BufRead) -> Result<Vec<String>, ParseError> {
+    let lines: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+    Ok(lines)
+}
+
+fn do_multiplies(instructions: String) -> i32 {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|(don't)(\(\))|(do)(\(\))").unwrap();
+    let mut results = 0;
+
+    let mut enable = true;
+
+    for c in re.captures_iter(instructions.as_str()) {
+        match c.extract() {
+            ("do()", _) => {
+                enable = true;
+            },
+            ("don't()", _) => {
+                enable = false;
+            }
+            (_, [m1, m2]) => {
+                if enable {
+                    results += m1.parse::<i32>().unwrap()*m2.parse::<i32>().unwrap();
+                }
+            }
+        }
+    }
+
+    results
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    const DAY: u8 = 3;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_instructions(reader)
+    }
+
+    fn part2(instructions: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(do_multiplies(instructions.join("")).to_string()))
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::{read_grid, Dir8, Grid, ParseError, Point, Solution, SolveError};
+use std::io::BufRead;
+
+fn walk_and_match(grid: &Grid<char>, start_char: char, target: &str) -> usize {
+    let target_chars: Vec<char> = target.chars().collect();
+
+    let mut matches = 0;
+
+    for start in grid.points() {
+        if grid[start] == start_char {
+            // For each starting character, check all directions
+            for dir in Dir8::ALL {
+                if match_in_direction(grid, start, dir, &target_chars) {
+                    matches += 1;
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+fn match_in_direction(grid: &Grid<char>, start: Point, dir: Dir8, target: &[char]) -> bool {
+    target
+        .iter()
+        .enumerate()
+        .all(|(i, ch)| grid.at(start.offset_by(dir, i as isize)) == Some(ch))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    const DAY: u8 = 4;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_grid(reader)
+    }
+
+    fn part1(grid: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(walk_and_match(&grid, 'X', "XMAS").to_string()))
+    }
+}
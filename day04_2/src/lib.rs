@@ -0,0 +1,49 @@
+use aoc_common::{read_grid, Grid, ParseError, Solution, SolveError};
+use std::io::BufRead;
+
+fn x_mas_count(grid: &Grid<char>) -> usize {
+    let mut matches = 0;
+
+    for row in 1..grid.rows()-1 {
+        for col in 1..grid.cols()-1 {
+            if grid[(row,col)] == 'A' {
+                // Check for one of four possible X-MAS's
+                if (grid[(row-1,col-1)] == 'M' &&
+                    grid[(row+1,col-1)] == 'S' &&
+                    grid[(row-1,col+1)] == 'M' &&
+                    grid[(row+1,col+1)] == 'S') ||
+                   (grid[(row-1,col-1)] == 'M' &&
+                    grid[(row+1,col-1)] == 'M' &&
+                    grid[(row-1,col+1)] == 'S' &&
+                    grid[(row+1,col+1)] == 'S') ||
+                   (grid[(row-1,col-1)] == 'S' &&
+                    grid[(row+1,col-1)] == 'S' &&
+                    grid[(row-1,col+1)] == 'M' &&
+                    grid[(row+1,col+1)] == 'M') ||
+                   (grid[(row-1,col-1)] == 'S' &&
+                    grid[(row+1,col-1)] == 'M' &&
+                    grid[(row-1,col+1)] == 'S' &&
+                    grid[(row+1,col+1)] == 'M') {
+                       matches += 1;
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    const DAY: u8 = 4;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_grid(reader)
+    }
+
+    fn part2(grid: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(x_mas_count(&grid).to_string()))
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::io::BufRead;
+
+type Rules = Vec<(i32, i32)>;
+type Updates = Vec<Vec<i32>>;
+
+fn parse_rule(idx: usize, line: &str) -> Result<(i32, i32), ParseError> {
+    if line.contains(',') && !line.contains('|') {
+        return Err(ParseError::at_column(idx + 1, line, 1, "a blank line before the updates"));
+    }
+
+    let ordered_pages = line.split("|").collect::<Vec<&str>>();
+    if ordered_pages.len() < 2 {
+        return Err(ParseError::at_end(idx + 1, line, "'|' and a second page number"));
+    }
+
+    Ok((
+        parse_token(idx + 1, line, ordered_pages[0], "a page number")?,
+        parse_token(idx + 1, line, ordered_pages[1], "a page number")?,
+    ))
+}
+
+fn parse_update(idx: usize, line: &str) -> Result<Vec<i32>, ParseError> {
+    if line.is_empty() {
+        return Err(ParseError::at_column(idx + 1, line, 1, "an update"));
+    }
+
+    let pages: Vec<i32> = collect_all(
+        line.split(",")
+            .map(|x| parse_token(idx + 1, line, x, "a page number")),
+    )?;
+    // Only an odd number of pages has a middle one
+    if pages.len().is_multiple_of(2) {
+        return Err(ParseError::at_end(idx + 1, line, "an odd number of pages"));
+    }
+
+    Ok(pages)
+}
+
+// Page ordering rules `a|b`, a blank line, then comma-separated updates
+fn read_input(reader: impl BufRead) -> Result<(Rules, Updates), ParseError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let numbered = lines.iter().enumerate();
+
+    let Some(blank) = lines.iter().position(|line| line.is_empty()) else {
+        let last = lines.len().max(1);
+        let text = lines.last().map_or("", |line| line.as_str());
+        return Err(ParseError::at_end(last, text, "a blank line, then the updates"));
+    };
+
+    let rules = collect_all(numbered.clone().take(blank).map(|(idx, line)| parse_rule(idx, line)));
+    let updates = collect_all(numbered.skip(blank + 1).map(|(idx, line)| parse_update(idx, line)));
+
+    match (rules, updates) {
+        (Ok(rules), Ok(updates)) => Ok((rules, updates)),
+        (rules, updates) => {
+            let errors = rules.err().into_iter().chain(updates.err()).collect();
+            Err(ParseError::from_all(errors).unwrap())
+        }
+    }
+}
+
+fn check_rule(update: &[i32], rule: &(i32, i32)) -> bool {
+    let i1 = update.iter().position(|n| *n == rule.0);
+    let i2 = update.iter().position(|n| *n == rule.1);
+
+    match (i1, i2) {
+        (Some(a), Some(b)) => a < b,    // Check rule
+        _ => true                       // One or more pages not in update, pass
+    }
+}
+
+fn check_update(update: &[i32], rules: &[(i32,i32)]) -> bool {
+    for rule in rules {
+        if !check_rule(update, rule) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn filter_updates(updates: Vec<Vec<i32>>, rules: Vec<(i32,i32)>) -> Vec<Vec<i32>> {
+    updates.into_iter().filter(|update| check_update(update, &rules)).collect::<Vec<Vec<i32>>>()
+}
+
+fn sum_midpoints(updates: Vec<Vec<i32>>) -> i32 {
+    let mut result = 0;
+
+    for update in updates {
+        assert!(update.len() % 2 == 1);
+        result += update[update.len() / 2];
+    }
+
+    result
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Rules, Updates);
+    const DAY: u8 = 5;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1((rules, updates): Self::Input) -> Result<Option<String>, SolveError> {
+        let correct_updates = filter_updates(updates, rules);
+        Ok(Some(sum_midpoints(correct_updates).to_string()))
+    }
+}
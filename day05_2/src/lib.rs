@@ -0,0 +1,113 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use petgraph::algo::toposort;
+use petgraph::graphmap::DiGraphMap;
+use itertools::izip;
+
+type Rules = Vec<(u32, u32)>;
+type Updates = Vec<Vec<u32>>;
+
+fn parse_rule(idx: usize, line: &str) -> Result<(u32, u32), ParseError> {
+    if line.contains(',') && !line.contains('|') {
+        return Err(ParseError::at_column(idx + 1, line, 1, "a blank line before the updates"));
+    }
+
+    let ordered_pages = line.split("|").collect::<Vec<&str>>();
+    if ordered_pages.len() < 2 {
+        return Err(ParseError::at_end(idx + 1, line, "'|' and a second page number"));
+    }
+
+    Ok((
+        parse_token(idx + 1, line, ordered_pages[0], "a page number")?,
+        parse_token(idx + 1, line, ordered_pages[1], "a page number")?,
+    ))
+}
+
+fn parse_update(idx: usize, line: &str) -> Result<Vec<u32>, ParseError> {
+    if line.is_empty() {
+        return Err(ParseError::at_column(idx + 1, line, 1, "an update"));
+    }
+
+    let pages: Vec<u32> = collect_all(
+        line.split(",")
+            .map(|x| parse_token(idx + 1, line, x, "a page number")),
+    )?;
+    // Only an odd number of pages has a middle one
+    if pages.len().is_multiple_of(2) {
+        return Err(ParseError::at_end(idx + 1, line, "an odd number of pages"));
+    }
+
+    Ok(pages)
+}
+
+// Page ordering rules `a|b`, a blank line, then comma-separated updates
+fn read_input(reader: impl BufRead) -> Result<(Rules, Updates), ParseError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let numbered = lines.iter().enumerate();
+
+    let Some(blank) = lines.iter().position(|line| line.is_empty()) else {
+        let last = lines.len().max(1);
+        let text = lines.last().map_or("", |line| line.as_str());
+        return Err(ParseError::at_end(last, text, "a blank line, then the updates"));
+    };
+
+    let rules = collect_all(numbered.clone().take(blank).map(|(idx, line)| parse_rule(idx, line)));
+    let updates = collect_all(numbered.skip(blank + 1).map(|(idx, line)| parse_update(idx, line)));
+
+    match (rules, updates) {
+        (Ok(rules), Ok(updates)) => Ok((rules, updates)),
+        (rules, updates) => {
+            let errors = rules.err().into_iter().chain(updates.err()).collect();
+            Err(ParseError::from_all(errors).unwrap())
+        }
+    }
+}
+
+fn midpoint(update: Vec<u32>) -> u32 {
+    assert!(update.len() % 2 == 1);
+    update[update.len() / 2]
+}
+
+fn correct_update(update: Vec<u32>, rules: &[(u32, u32)]) -> Vec<u32> {
+    // Find correct order for this set of pages
+    let update_map: HashSet<u32> = update.into_iter().collect();
+
+    let relevant_rules = rules
+        .iter()
+        .filter(|(a, b)| update_map.contains(a) && update_map.contains(b))
+        .collect::<Vec<&(u32, u32)>>();
+
+    let g = DiGraphMap::<u32, ()>::from_edges(relevant_rules);
+    toposort(&g, None).unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Rules, Updates);
+    const DAY: u8 = 5;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part2((rules, updates): Self::Input) -> Result<Option<String>, SolveError> {
+        let corrected = updates
+            .clone()
+            .into_iter()
+            .map(|x| correct_update(x, &rules))
+            .collect::<Vec<Vec<u32>>>();
+
+        // Sum midpoints of only reordered updates
+        let mut midpoint_total = 0;
+        for (update_i, corrected_i) in izip!(updates, corrected) {
+            if update_i != corrected_i {
+                midpoint_total += midpoint(corrected_i);
+            }
+        }
+
+        Ok(Some(midpoint_total.to_string()))
+    }
+}
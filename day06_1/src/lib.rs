@@ -0,0 +1,23 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use day06_patrol::{read_lab, Lab};
+use std::io::BufRead;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+    const DAY: u8 = 6;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_lab(reader)
+    }
+
+    fn part1(lab: Self::Input) -> Result<Option<String>, SolveError> {
+        let route = lab.patrol();
+        if route.loops {
+            return Err(SolveError::new("the guard never leaves the map"));
+        }
+
+        Ok(Some(route.visited_count().to_string()))
+    }
+}
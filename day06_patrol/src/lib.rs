@@ -0,0 +1,246 @@
+// Day 6 guard patrols, shared by both parts. The guard walks forwards until
+// the cell ahead is an obstacle, then turns right, until it walks off the map.
+//
+// Part 2 puts one extra obstruction down and asks whether the guard then
+// loops. Only a cell the guard would otherwise walk onto can change its
+// route, so those are the only ones tried, and each trial starts from where
+// the guard is just before first reaching that cell: everything up to there
+// is the same as without it. From then on the guard moves a whole straight
+// run at a time using tables of where it next stops in each direction, so a
+// trial costs one step per turn rather than per cell.
+//
+// The variant module has patrols under other rules, with several guards and
+// portals.
+use aoc_common::{read_grid, Dir4, Direction, Grid, ParseError, Point};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+pub mod render;
+pub mod variant;
+
+use variant::{find_guards, walk, Rules};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Point,
+    pub dir: Dir4,
+}
+
+impl Guard {
+    pub fn ahead(self) -> Point {
+        self.pos.offset(self.dir)
+    }
+}
+
+// Where a guard goes before it leaves the map or starts going round in
+// circles: every cell it visits after the first, in order, along with the
+// guard just before the move that first takes it there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub visits: Vec<(Point, Guard)>,
+    pub loops: bool,
+}
+
+// The guard's walk with an extra obstruction that makes it loop, one state
+// per step or turn: those before the loop, then one time round it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopPath {
+    pub obstruction: Point,
+    pub lead_in: Vec<Guard>,
+    pub cycle: Vec<Guard>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    obstacles: Grid<bool>,
+    // Where a guard walking each way from a cell stops with an obstacle
+    // ahead of it, or None if it walks off the map
+    stops: Grid<[Option<Point>; 4]>,
+    pub start: Guard,
+}
+
+impl Route {
+    // How many distinct cells the guard visits, counting where it starts
+    pub fn visited_count(&self) -> usize {
+        self.visits.len() + 1
+    }
+}
+
+impl Lab {
+    pub fn new(obstacles: Grid<bool>, start: Guard) -> Self {
+        let mut stops: Grid<[Option<Point>; 4]> = Grid::new(obstacles.rows(), obstacles.cols());
+
+        for dir in Dir4::ALL {
+            // Fill in each cell after the one ahead of it
+            let mut points: Vec<Point> = obstacles.points().collect();
+            if matches!(dir, Dir4::Down | Dir4::Right) {
+                points.reverse();
+            }
+
+            for p in points {
+                let ahead = p.offset(dir);
+                stops[p][dir as usize] = match obstacles.at(ahead) {
+                    None => None,
+                    Some(true) => Some(p),
+                    Some(false) => stops[ahead][dir as usize],
+                };
+            }
+        }
+
+        Lab {
+            obstacles,
+            stops,
+            start,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.obstacles.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.obstacles.cols()
+    }
+
+    pub fn is_obstacle(&self, p: Point) -> bool {
+        self.obstacles.at(p) == Some(&true)
+    }
+
+    // Walk the guard until it leaves the map or loops
+    pub fn patrol(&self) -> Route {
+        walk(&self.obstacles, self.start, &Rules::default())
+    }
+
+    // Where the guard stops walking from its current cell, with extra as
+    // another obstruction
+    fn next_stop(&self, guard: Guard, extra: Point) -> Option<Point> {
+        let stop = self.stops[guard.pos][guard.dir as usize];
+
+        // How many steps ahead extra is, if it's straight ahead at all
+        let diff = extra - guard.pos;
+        let delta = guard.dir.delta();
+        let steps = if guard.dir.is_vertical() {
+            (diff.col == 0).then_some(diff.row * delta.row)
+        } else {
+            (diff.row == 0).then_some(diff.col * delta.col)
+        };
+
+        match (steps, stop) {
+            (Some(steps), None) if steps > 0 => Some(guard.pos.offset_by(guard.dir, steps - 1)),
+            (Some(steps), Some(stop))
+                if steps > 0 && steps <= guard.pos.manhattan(stop) as isize =>
+            {
+                Some(guard.pos.offset_by(guard.dir, steps - 1))
+            }
+            _ => stop,
+        }
+    }
+
+    // Whether a guard in the given state, facing obstruction, goes round in
+    // circles forever. turns is scratch space for the states the guard has
+    // been in when turning, stamped with trial so it needn't be cleared.
+    fn loops_from(
+        &self,
+        mut guard: Guard,
+        obstruction: Point,
+        turns: &mut Grid<[u32; 4]>,
+        trial: u32,
+    ) -> bool {
+        loop {
+            let seen = &mut turns[guard.pos][guard.dir as usize];
+            if *seen == trial {
+                return true;
+            }
+            *seen = trial;
+            guard.dir = guard.dir.turn_right();
+
+            match self.next_stop(guard, obstruction) {
+                Some(stop) => guard.pos = stop,
+                None => return false,
+            }
+        }
+    }
+
+    // The guard's walk with obstruction added, a cell at a time, or None if
+    // it doesn't loop (or obstruction isn't on an empty cell other than the
+    // guard's)
+    pub fn loop_path(&self, obstruction: Point) -> Option<LoopPath> {
+        if self.obstacles.at(obstruction) != Some(&false) || obstruction == self.start.pos {
+            return None;
+        }
+
+        // Where each state first came in states
+        let mut seen = HashMap::new();
+        let mut states = vec![];
+        let mut guard = self.start;
+
+        while !seen.contains_key(&guard) {
+            seen.insert(guard, states.len());
+            states.push(guard);
+
+            let ahead = guard.ahead();
+            if ahead == obstruction || self.is_obstacle(ahead) {
+                guard.dir = guard.dir.turn_right();
+            } else if self.obstacles.contains(ahead) {
+                guard.pos = ahead;
+            } else {
+                return None;
+            }
+        }
+
+        let cycle = states.split_off(seen[&guard]);
+        Some(LoopPath {
+            obstruction,
+            lead_in: states,
+            cycle,
+        })
+    }
+
+    // Every cell where one more obstruction sends the guard into a loop, in
+    // the order the guard would first have reached them
+    pub fn loop_obstructions(&self) -> Vec<Point> {
+        let mut turns: Grid<[u32; 4]> = Grid::new(self.rows(), self.cols());
+
+        self.patrol()
+            .visits
+            .into_iter()
+            .zip(1..)
+            .filter(|&((obstruction, guard), trial)| {
+                self.loops_from(guard, obstruction, &mut turns, trial)
+            })
+            .map(|((obstruction, _), _)| obstruction)
+            .collect()
+    }
+}
+
+// A map of '#' obstacles and '.' floor, with one guard: '^', '>', 'v' or '<'
+// for the way it starts out facing
+pub fn read_lab(reader: impl BufRead) -> Result<Lab, ParseError> {
+    let grid: Grid<char> = read_grid(reader)?;
+    let row_text = |row: usize| grid.iter_row(row).collect::<String>();
+
+    let start = match find_guards(&grid)[..] {
+        [guard] => guard,
+        [] => {
+            let expected = "'^', '>', 'v' or '<' marking the guard";
+            return Err(ParseError::at_column(grid.rows() + 1, "", 1, expected));
+        }
+        [_, ref rest @ ..] => {
+            let errors = rest
+                .iter()
+                .map(|guard| {
+                    let (row, col) = guard.pos.to_index().unwrap();
+                    ParseError::at_column(row + 1, &row_text(row), col + 1, "only one guard")
+                })
+                .collect();
+            return Err(ParseError::Multiple(errors));
+        }
+    };
+
+    let mut obstacles: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    for p in grid.points() {
+        obstacles[p] = grid[p] == '#';
+    }
+
+    Ok(Lab::new(obstacles, start))
+}
@@ -0,0 +1,146 @@
+use aoc_common::{Dir4, Grid, InputSource, Point};
+use day06_patrol::read_lab;
+use day06_patrol::render::render;
+use day06_patrol::variant::{read_variant, Bounce};
+use std::env;
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} [--show <row>,<col>] [--colour] <input>", program);
+    eprintln!("       {} --guards [--bounce <rule>] <input>", program);
+    eprintln!();
+    eprintln!("<input> is a file path, - for stdin, or --input-str <text>");
+    eprintln!("Lists every obstruction that makes the guard loop, as <row>,<col> from 0");
+    eprintln!("--show draws the loop an obstruction at <row>,<col> makes instead");
+    eprintln!("--colour highlights the loop and obstruction with ANSI colours");
+    eprintln!("--guards follows every guard (^ > v <) through lettered portal pairs");
+    eprintln!("<rule> is what guards do at an obstacle: right (default), left or reverse");
+    std::process::exit(1);
+}
+
+fn parse_point(text: &str) -> Option<Point> {
+    let (row, col) = text.split_once(',')?;
+    Some(Point::new(
+        row.trim().parse().ok()?,
+        col.trim().parse().ok()?,
+    ))
+}
+
+fn parse_bounce(rule: &str) -> Option<Bounce> {
+    match rule {
+        "right" => Some(Dir4::turn_right),
+        "left" => Some(Dir4::turn_left),
+        "reverse" => Some(Dir4::reverse),
+        _ => None,
+    }
+}
+
+// Follow every guard on a variant map, printing how far each gets
+fn patrol_guards(source: &InputSource, bounce: Bounce) {
+    let mut variant = source
+        .reader()
+        .and_then(read_variant)
+        .map_err(|err| err.with_file(source.path()))
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+    variant.rules.bounce = bounce;
+
+    let mut any: Grid<bool> = Grid::new(variant.obstacles.rows(), variant.obstacles.cols());
+    for (n, (guard, route)) in variant.guards.iter().zip(variant.patrol()).enumerate() {
+        any[guard.pos] = true;
+        for &(cell, _) in &route.visits {
+            any[cell] = true;
+        }
+
+        println!(
+            "guard {} at {},{} facing {}: {} cells, {}",
+            n + 1,
+            guard.pos.row,
+            guard.pos.col,
+            guard.dir.to_char(),
+            route.visited_count(),
+            if route.loops { "loops" } else { "leaves" }
+        );
+    }
+    println!("total {}", any.iter().filter(|&&seen| seen).count());
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let name = &args[0];
+
+    let mut show = None;
+    let mut colour = false;
+    let mut guards = false;
+    let mut bounce: Bounce = Dir4::turn_right;
+    let mut rest = &args[1..];
+    loop {
+        match rest {
+            [flag, point, tail @ ..] if flag == "--show" => {
+                show = Some(parse_point(point).unwrap_or_else(|| {
+                    eprintln!("expected <row>,<col>, got '{}'", point);
+                    usage(name);
+                }));
+                rest = tail;
+            }
+            [flag, tail @ ..] if flag == "--colour" => {
+                colour = true;
+                rest = tail;
+            }
+            [flag, tail @ ..] if flag == "--guards" => {
+                guards = true;
+                rest = tail;
+            }
+            [flag, rule, tail @ ..] if flag == "--bounce" => {
+                bounce = parse_bounce(rule).unwrap_or_else(|| {
+                    eprintln!("unknown rule '{}'", rule);
+                    usage(name);
+                });
+                rest = tail;
+            }
+            _ => break,
+        }
+    }
+
+    let Some((source, [])) = InputSource::from_args(rest) else {
+        usage(name);
+    };
+    if guards {
+        patrol_guards(&source, bounce);
+        return;
+    }
+
+    let lab = source
+        .reader()
+        .and_then(read_lab)
+        .map_err(|err| err.with_file(source.path()))
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+
+    if let Some(obstruction) = show {
+        let Some(path) = lab.loop_path(obstruction) else {
+            eprintln!(
+                "an obstruction at {},{} doesn't make the guard loop",
+                obstruction.row, obstruction.col
+            );
+            std::process::exit(1);
+        };
+        print!("{}", render(&lab, &path, colour));
+        println!(
+            "{} steps and turns round the loop, after {} leading into it",
+            path.cycle.len(),
+            path.lead_in.len()
+        );
+        return;
+    }
+
+    let mut obstructions = lab.loop_obstructions();
+    obstructions.sort();
+    for p in &obstructions {
+        println!("{},{}", p.row, p.col);
+    }
+    println!("total {}", obstructions.len());
+}
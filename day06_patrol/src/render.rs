@@ -0,0 +1,67 @@
+// Draw a guard's loop the way the puzzle does: '|' and '-' where it walks up
+// and down or across, '+' where it turns or crosses its own path, and 'O'
+// for the extra obstruction. With colour, the loop itself is picked out from
+// the walk leading into it.
+use crate::{Lab, LoopPath};
+use aoc_common::{Grid, Point};
+
+const LOOP: &str = "\x1b[33m";
+const OBSTRUCTION: &str = "\x1b[1;31m";
+const RESET: &str = "\x1b[0m";
+
+// Which ways the guard has faced in a cell
+#[derive(Debug, Clone, Copy, Default)]
+struct Marks {
+    vertical: bool,
+    horizontal: bool,
+    in_loop: bool,
+}
+
+pub fn render(lab: &Lab, path: &LoopPath, colour: bool) -> String {
+    let mut marks: Grid<Marks> = Grid::new(lab.rows(), lab.cols());
+    let states = path.lead_in.iter().map(|guard| (guard, false));
+    for (guard, in_loop) in states.chain(path.cycle.iter().map(|guard| (guard, true))) {
+        let cell = &mut marks[guard.pos];
+        if guard.dir.is_vertical() {
+            cell.vertical = true;
+        } else {
+            cell.horizontal = true;
+        }
+        cell.in_loop |= in_loop;
+    }
+
+    let mut text = String::new();
+    for row in 0..lab.rows() {
+        for col in 0..lab.cols() {
+            let p = Point::from((row, col));
+            let cell = marks[p];
+            let c = if p == path.obstruction {
+                'O'
+            } else if lab.is_obstacle(p) {
+                '#'
+            } else if p == lab.start.pos {
+                lab.start.dir.to_char()
+            } else {
+                match (cell.vertical, cell.horizontal) {
+                    (true, true) => '+',
+                    (true, false) => '|',
+                    (false, true) => '-',
+                    (false, false) => '.',
+                }
+            };
+
+            let highlight = match c {
+                'O' => Some(OBSTRUCTION),
+                '|' | '-' | '+' if cell.in_loop => Some(LOOP),
+                _ => None,
+            };
+            match highlight {
+                Some(code) if colour => text += &format!("{}{}{}", code, c, RESET),
+                _ => text.push(c),
+            }
+        }
+        text.push('\n');
+    }
+
+    text
+}
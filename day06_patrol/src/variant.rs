@@ -0,0 +1,176 @@
+// Patrols under rules other than the puzzle's. A map can hold any number of
+// guards, each starting as '^', '>', 'v' or '<', and pairs of portals marked
+// with the same lowercase letter (other than v). Stepping onto a portal
+// carries a guard straight to the other one of its pair, still facing the
+// same way. What a guard does at an obstacle is up to the rules:
+//
+//     let mut variant = read_variant(reader)?;
+//     variant.rules.bounce = Dir4::turn_left;
+//     for route in variant.patrol() { ... }
+//
+// Guards don't get in each other's way, so each one's patrol is the same as
+// if it were alone.
+use crate::{Guard, Route};
+use aoc_common::{read_grid, Dir4, Grid, ParseError, Point};
+use std::collections::{BTreeMap, HashMap};
+use std::io::BufRead;
+
+// Which way a guard faces after finding an obstacle ahead, e.g.
+// Dir4::turn_right for the puzzle's guard or Dir4::reverse to go back
+pub type Bounce = fn(Dir4) -> Dir4;
+
+#[derive(Debug, Clone)]
+pub struct Rules {
+    pub bounce: Bounce,
+    // Each portal's partner, both ways round
+    pub portals: HashMap<Point, Point>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            bounce: Dir4::turn_right,
+            portals: HashMap::new(),
+        }
+    }
+}
+
+impl Rules {
+    pub fn add_portal(&mut self, a: Point, b: Point) {
+        self.portals.insert(a, b);
+        self.portals.insert(b, a);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Variant {
+    pub obstacles: Grid<bool>,
+    pub guards: Vec<Guard>,
+    pub rules: Rules,
+}
+
+impl Variant {
+    // Every guard's route, in the order they appear on the map
+    pub fn patrol(&self) -> Vec<Route> {
+        self.guards
+            .iter()
+            .map(|&guard| walk(&self.obstacles, guard, &self.rules))
+            .collect()
+    }
+}
+
+// Walk a guard until it leaves the map or comes back to a cell it has
+// already been in facing the same way. A portal counts as visited when the
+// guard steps onto it, as well as the one it comes out of.
+pub(crate) fn walk(obstacles: &Grid<bool>, start: Guard, rules: &Rules) -> Route {
+    let mut states: Grid<[bool; 4]> = Grid::new(obstacles.rows(), obstacles.cols());
+    let mut cells: Grid<bool> = Grid::new(obstacles.rows(), obstacles.cols());
+    cells[start.pos] = true;
+    let mut visits = vec![];
+    let mut guard = start;
+
+    loop {
+        let state = &mut states[guard.pos][guard.dir as usize];
+        if *state {
+            return Route {
+                visits,
+                loops: true,
+            };
+        }
+        *state = true;
+
+        let ahead = guard.ahead();
+        match obstacles.at(ahead) {
+            None => {
+                return Route {
+                    visits,
+                    loops: false,
+                }
+            }
+            Some(true) => guard.dir = (rules.bounce)(guard.dir),
+            Some(false) => {
+                let next = rules.portals.get(&ahead).copied().unwrap_or(ahead);
+                for cell in [ahead, next] {
+                    if !cells[cell] {
+                        cells[cell] = true;
+                        visits.push((cell, guard));
+                    }
+                }
+                guard.pos = next;
+            }
+        }
+    }
+}
+
+// Every guard on a map, in reading order
+pub(crate) fn find_guards(grid: &Grid<char>) -> Vec<Guard> {
+    grid.points()
+        .filter_map(|pos| {
+            Some(Guard {
+                pos,
+                dir: Dir4::from_char(grid[pos])?,
+            })
+        })
+        .collect()
+}
+
+// A map of '#' obstacles and '.' floor, with any number of guards and
+// lettered portal pairs
+pub fn read_variant(reader: impl BufRead) -> Result<Variant, ParseError> {
+    let grid: Grid<char> = read_grid(reader)?;
+    let row_text = |row: usize| grid.iter_row(row).collect::<String>();
+
+    let guards = find_guards(&grid);
+    if guards.is_empty() {
+        let expected = "a guard, '^', '>', 'v' or '<'";
+        return Err(ParseError::at_column(grid.rows() + 1, "", 1, expected));
+    }
+
+    let mut obstacles: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    let mut letters: BTreeMap<char, Vec<Point>> = BTreeMap::new();
+    for p in grid.points() {
+        obstacles[p] = grid[p] == '#';
+        if grid[p].is_ascii_lowercase() && grid[p] != 'v' {
+            letters.entry(grid[p]).or_default().push(p);
+        }
+    }
+
+    let mut rules = Rules::default();
+    let mut errors = vec![];
+    for (letter, ends) in letters {
+        match ends[..] {
+            [a, b] => rules.add_portal(a, b),
+            [a] => {
+                let expected = format!("another '{}' for this portal to lead to", letter);
+                let (row, col) = a.to_index().unwrap();
+                errors.push(ParseError::at_column(
+                    row + 1,
+                    &row_text(row),
+                    col + 1,
+                    &expected,
+                ));
+            }
+            _ => {
+                let expected = format!("only two '{}' portals", letter);
+                for p in &ends[2..] {
+                    let (row, col) = p.to_index().unwrap();
+                    errors.push(ParseError::at_column(
+                        row + 1,
+                        &row_text(row),
+                        col + 1,
+                        &expected,
+                    ));
+                }
+            }
+        }
+    }
+    if let Some(err) = ParseError::from_all(errors) {
+        return Err(err);
+    }
+
+    Ok(Variant {
+        obstacles,
+        guards,
+        rules,
+    })
+}
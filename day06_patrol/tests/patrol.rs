@@ -0,0 +1,211 @@
+// The day 6 patrol simulator
+use aoc_common::{Dir4, Grid, Point};
+use aoc_testing::Lcg;
+use day06_patrol::render::render;
+use day06_patrol::variant::{read_variant, Variant};
+use day06_patrol::{read_lab, Guard, Lab};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+fn example() -> Lab {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../examples/day06/example.txt");
+    read_lab(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+// Walk one cell at a time, as the puzzle describes
+fn loops_slowly(obstacles: &Grid<bool>, start: Guard) -> bool {
+    let mut guard = start;
+    let mut seen = HashSet::new();
+
+    while seen.insert(guard) {
+        let ahead = guard.pos.offset(guard.dir);
+        match obstacles.at(ahead) {
+            None => return false,
+            Some(true) => guard.dir = guard.dir.turn_right(),
+            Some(false) => guard.pos = ahead,
+        }
+    }
+
+    true
+}
+
+#[test]
+fn example_patrol() {
+    let lab = example();
+    let route = lab.patrol();
+    assert!(!route.loops);
+    assert_eq!(route.visited_count(), 41);
+
+    // Every cell is first reached from the one before it
+    for &(cell, guard) in &route.visits {
+        assert_eq!(guard.pos.offset(guard.dir), cell);
+    }
+
+    let mut obstructions = lab.loop_obstructions();
+    obstructions.sort();
+    let expected: Vec<Point> = [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]
+        .into_iter()
+        .map(Point::from)
+        .collect();
+    assert_eq!(obstructions, expected);
+}
+
+#[test]
+fn obstruction_search_matches_walking_every_cell() {
+    let mut rng = Lcg::new(6);
+
+    for _ in 0..200 {
+        let (rows, cols) = (rng.below(12) as usize + 1, rng.below(12) as usize + 1);
+        let density = rng.below(4) + 1;
+        let mut obstacles: Grid<bool> = Grid::new(rows, cols);
+        for p in obstacles.points() {
+            obstacles[p] = rng.below(10) < density;
+        }
+        let pos = Point::from((
+            rng.below(rows as u64) as usize,
+            rng.below(cols as u64) as usize,
+        ));
+        obstacles[pos] = false;
+        let start = Guard { pos, dir: Dir4::Up };
+
+        let lab = Lab::new(obstacles.clone(), start);
+        let route = lab.patrol();
+        assert_eq!(route.loops, loops_slowly(&obstacles, start));
+        if route.loops {
+            continue;
+        }
+
+        let expected: HashSet<Point> = obstacles
+            .points()
+            .filter(|&p| p != pos && !obstacles[p])
+            .filter(|&p| {
+                let mut blocked = obstacles.clone();
+                blocked[p] = true;
+                loops_slowly(&blocked, start)
+            })
+            .collect();
+        let found = lab.loop_obstructions();
+        assert_eq!(found.len(), expected.len());
+        assert_eq!(found.into_iter().collect::<HashSet<_>>(), expected);
+    }
+}
+
+#[test]
+fn loops_render_like_the_puzzle() {
+    let lab = example();
+    let path = lab.loop_path(Point::new(6, 3)).unwrap();
+    assert!(path.lead_in.is_empty());
+
+    let expected = "\
+....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#...
+";
+    assert_eq!(render(&lab, &path, false), expected);
+
+    // Only the obstructions found by the search make loops
+    let obstructions = lab.loop_obstructions();
+    for p in lab_points(&lab) {
+        let path = lab.loop_path(p);
+        assert_eq!(path.is_some(), obstructions.contains(&p), "{:?}", p);
+        if let Some(path) = path {
+            // Going round once more starts the loop again
+            let last = *path.cycle.last().unwrap();
+            let first = path.cycle[0];
+            assert!(
+                last.pos.offset(last.dir) == first.pos
+                    || (last.pos == first.pos && last.dir.turn_right() == first.dir)
+            );
+        }
+    }
+    assert_eq!(lab.loop_path(lab.start.pos), None);
+    assert_eq!(lab.loop_path(Point::new(0, 4)), None);
+    assert_eq!(lab.loop_path(Point::new(-1, 4)), None);
+}
+
+fn lab_points(lab: &Lab) -> impl Iterator<Item = Point> {
+    let cols = lab.cols();
+    (0..lab.rows() * cols).map(move |i| Point::from((i / cols, i % cols)))
+}
+
+fn variant(map: &str) -> Variant {
+    read_variant(map.as_bytes()).unwrap()
+}
+
+// Each guard's visited count and whether it loops
+fn summary(variant: &Variant) -> Vec<(usize, bool)> {
+    variant
+        .patrol()
+        .iter()
+        .map(|route| (route.visited_count(), route.loops))
+        .collect()
+}
+
+#[test]
+fn guards_start_facing_any_way() {
+    let lab = read_lab("..#\n..>\n...\n".as_bytes()).unwrap();
+    assert_eq!(lab.start.dir, Dir4::Right);
+    assert_eq!(lab.patrol().visited_count(), 1);
+
+    assert!(read_lab("...\n".as_bytes()).is_err());
+    assert!(read_lab("^.<\n".as_bytes()).is_err());
+}
+
+#[test]
+fn variant_rules_change_every_guards_patrol() {
+    let map = "\
+.a..#....
+.....v..#
+#...<....
+.......a.
+>....#...
+";
+    let mut variant = variant(map);
+    assert_eq!(variant.guards.len(), 3);
+    assert_eq!(summary(&variant), [(8, false), (10, false), (5, false)]);
+
+    // Guard 2 goes up through a at the top and out of the other one
+    let route = &variant.patrol()[1];
+    let cells: Vec<Point> = route.visits.iter().map(|&(cell, _)| cell).collect();
+    assert!(cells.ends_with(&[
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(3, 7),
+        Point::new(2, 7),
+        Point::new(1, 7),
+        Point::new(0, 7),
+    ]));
+
+    variant.rules.bounce = Dir4::turn_left;
+    assert_eq!(summary(&variant), [(8, false), (6, false), (12, false)]);
+    variant.rules.bounce = Dir4::reverse;
+    assert_eq!(summary(&variant), [(4, false), (8, false), (5, false)]);
+}
+
+#[test]
+fn variant_guards_can_loop() {
+    let mut pacing = variant("#.>.#\n.....\n");
+    pacing.rules.bounce = Dir4::reverse;
+    assert_eq!(summary(&pacing), [(3, true)]);
+
+    // Into one a, out of the other and off the map
+    let through = variant(">.a.\n...a\n");
+    assert_eq!(summary(&through), [(4, false)]);
+    // Out of the left portal and along into the right one forever
+    let circling = variant("a>.a\n");
+    assert_eq!(summary(&circling), [(4, true)]);
+
+    // Portals must pair up, and v is a guard not a portal
+    assert!(read_variant("a.>\n".as_bytes()).is_err());
+    assert!(read_variant("a.a\na.>\n".as_bytes()).is_err());
+    assert_eq!(variant("v.v\n").guards.len(), 2);
+}
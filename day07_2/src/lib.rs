@@ -0,0 +1,19 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use day07_solver::{read_equations, sum_calibration_values, Equation, Operator};
+use std::io::BufRead;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    const DAY: u8 = 7;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_equations(reader)
+    }
+
+    fn part2(equations: Self::Input) -> Result<Option<String>, SolveError> {
+        let ops = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+        Ok(Some(sum_calibration_values(&equations, &ops).to_string()))
+    }
+}
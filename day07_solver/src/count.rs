@@ -0,0 +1,201 @@
+// Count every operator assignment that solves an equation, and list them.
+//
+// Both work forwards over the operands, memoising on how many ways there are
+// to finish from each (position, value so far) pair. Different assignments
+// often reach the same prefix value, so the count doesn't grow with the
+// number of assignments. Enumeration only descends into branches the counts
+// say can be finished, so it never has to back out of a dead end and each
+// solution costs at most one step per operand.
+use crate::{Equation, Mode, Operator, Partial};
+use std::collections::HashMap;
+
+// The value of the operands used so far
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum State {
+    LeftToRight(u64),
+    Precedence(Partial),
+}
+
+impl State {
+    fn new(mode: Mode, first: u64) -> Self {
+        match mode {
+            Mode::LeftToRight => State::LeftToRight(first),
+            Mode::Precedence => State::Precedence(Partial::new(first)),
+        }
+    }
+
+    fn push(self, op: Operator, b: u64) -> Option<Self> {
+        match self {
+            State::LeftToRight(acc) => op.apply(acc, b).map(State::LeftToRight),
+            State::Precedence(partial) => partial.push(op, b).map(State::Precedence),
+        }
+    }
+
+    fn value(self) -> Option<u64> {
+        match self {
+            State::LeftToRight(acc) => Some(acc),
+            State::Precedence(partial) => partial.value(),
+        }
+    }
+}
+
+struct Counter<'a> {
+    equation: &'a Equation,
+    ops: &'a [Operator],
+    // Whether anything from each position on could make the value smaller.
+    // If not, a left-to-right value already past the test value is a dead end.
+    can_shrink: Vec<bool>,
+    memo: HashMap<(usize, State), u128>,
+}
+
+impl<'a> Counter<'a> {
+    fn new(equation: &'a Equation, ops: &'a [Operator]) -> Self {
+        let shrinking_op = ops.contains(&Operator::Subtract) || ops.contains(&Operator::Divide);
+        let mut can_shrink = vec![false; equation.operands.len() + 1];
+        for pos in (0..equation.operands.len()).rev() {
+            let zero = equation.operands[pos] == 0 && ops.contains(&Operator::Multiply);
+            can_shrink[pos] = shrinking_op || zero || can_shrink[pos + 1];
+        }
+
+        Counter {
+            equation,
+            ops,
+            can_shrink,
+            memo: HashMap::new(),
+        }
+    }
+
+    // The number of ways to finish from state, having used operands[..pos]
+    fn count(&mut self, pos: usize, state: State) -> u128 {
+        let operands = &self.equation.operands;
+        if pos == operands.len() {
+            return (state.value() == Some(self.equation.testval)) as u128;
+        }
+        if let State::LeftToRight(acc) = state {
+            if acc > self.equation.testval && !self.can_shrink[pos] {
+                return 0;
+            }
+        }
+        if let Some(&count) = self.memo.get(&(pos, state)) {
+            return count;
+        }
+
+        let b = operands[pos];
+        let mut total = 0;
+        for &op in self.ops {
+            if let Some(next) = state.push(op, b) {
+                total += self.count(pos + 1, next);
+            }
+        }
+
+        self.memo.insert((pos, state), total);
+        total
+    }
+}
+
+// Every solution of an equation, found lazily
+pub struct Solutions<'a> {
+    counter: Counter<'a>,
+    // The state after each operand so far, and the next operator to try
+    // after it
+    stack: Vec<(State, usize)>,
+    chosen: Vec<Operator>,
+}
+
+impl Iterator for Solutions<'_> {
+    type Item = Vec<Operator>;
+
+    fn next(&mut self) -> Option<Vec<Operator>> {
+        let operands = &self.counter.equation.operands;
+
+        while let Some(&(state, next_op)) = self.stack.last() {
+            let pos = self.stack.len();
+            if pos == operands.len() {
+                // Only complete solutions are ever pushed this far
+                let solution = self.chosen.clone();
+                self.stack.pop();
+                self.chosen.pop();
+                return Some(solution);
+            }
+            if next_op == self.counter.ops.len() {
+                self.stack.pop();
+                self.chosen.pop();
+                continue;
+            }
+
+            self.stack.last_mut().unwrap().1 += 1;
+            let op = self.counter.ops[next_op];
+            let Some(next) = state.push(op, operands[pos]) else {
+                continue;
+            };
+            if self.counter.count(pos + 1, next) > 0 {
+                self.chosen.push(op);
+                self.stack.push((next, 0));
+            }
+        }
+
+        None
+    }
+}
+
+impl Equation {
+    // How many assignments of operators from ops make the equation true
+    pub fn count_solutions(&self, mode: Mode, ops: &[Operator]) -> u128 {
+        Counter::new(self, ops).count(1, State::new(mode, self.operands[0]))
+    }
+
+    // Every assignment of operators from ops making the equation true, in
+    // the order ops lists them
+    pub fn solutions<'a>(&'a self, mode: Mode, ops: &'a [Operator]) -> Solutions<'a> {
+        let mut counter = Counter::new(self, ops);
+        let start = State::new(mode, self.operands[0]);
+        let stack = if counter.count(1, start) > 0 {
+            vec![(start, 0)]
+        } else {
+            vec![]
+        };
+
+        Solutions {
+            counter,
+            stack,
+            chosen: vec![],
+        }
+    }
+}
+
+// How many ways each equation can be solved, for the ones that can
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationReport {
+    // The sum of the solvable test values
+    pub total: u128,
+    // The index of each solvable equation and its number of solutions
+    pub counts: Vec<(usize, u128)>,
+}
+
+impl CalibrationReport {
+    // The equations with more than one solution
+    pub fn ambiguous(&self) -> impl Iterator<Item = (usize, u128)> + '_ {
+        self.counts.iter().copied().filter(|&(_, count)| count > 1)
+    }
+}
+
+pub fn calibration_report(
+    mode: Mode,
+    equations: &[Equation],
+    ops: &[Operator],
+) -> CalibrationReport {
+    let mut report = CalibrationReport {
+        total: 0,
+        counts: vec![],
+    };
+
+    for (idx, equation) in equations.iter().enumerate() {
+        let count = equation.count_solutions(mode, ops);
+        if count > 0 {
+            report.total += equation.testval as u128;
+            report.counts.push((idx, count));
+        }
+    }
+
+    report
+}
@@ -0,0 +1,367 @@
+// Day 7 calibration equations, shared by both parts. An equation is solvable
+// if operators between its operands, evaluated left to right, give the test
+// value. Which operators are allowed is up to the caller, and they can also
+// be evaluated with the usual precedence instead.
+//
+// Rather than trying every assignment, the left-to-right solver works from
+// the right: the last operator must turn some value into the test value
+// using the last operand, so undo it (subtract, divide if divisible, strip a
+// matching suffix) and recurse on what's left. Most branches die immediately.
+//
+// Every intermediate value has to fit in a u64. An assignment that overflows
+// or goes below zero on the way doesn't count, and nor does one dividing by
+// zero or with a remainder.
+use aoc_common::{collect_all, parse_token, ParseError};
+use std::fmt;
+use std::io::BufRead;
+
+pub mod count;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    pub testval: u64,
+    pub operands: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+    Subtract,
+    // Exact division only
+    Divide,
+}
+
+// How an assignment of operators is evaluated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    // Strictly left to right, as in the puzzle
+    #[default]
+    LeftToRight,
+    // || binds tightest, then * and /, then + and -, each left to right
+    Precedence,
+}
+
+// The smallest power of ten above b, so that a || b = a * shift + b
+fn concat_shift(b: u64) -> Option<u64> {
+    let mut shift = 10_u64;
+    while shift <= b {
+        shift = shift.checked_mul(10)?;
+    }
+    Some(shift)
+}
+
+impl Operator {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Multiply => "*",
+            Operator::Concatenate => "||",
+            Operator::Subtract => "-",
+            Operator::Divide => "/",
+        }
+    }
+
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "+" => Some(Operator::Add),
+            "*" => Some(Operator::Multiply),
+            "||" => Some(Operator::Concatenate),
+            "-" => Some(Operator::Subtract),
+            "/" => Some(Operator::Divide),
+            _ => None,
+        }
+    }
+
+    // a op b, or None if it doesn't fit in a u64
+    pub fn apply(self, a: u64, b: u64) -> Option<u64> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+            Operator::Concatenate => match concat_shift(b) {
+                Some(shift) => a.checked_mul(shift)?.checked_add(b),
+                // Only a leading 0 leaves room for a b this long
+                None => (a == 0).then_some(b),
+            },
+            Operator::Subtract => a.checked_sub(b),
+            Operator::Divide if b != 0 && a.is_multiple_of(b) => Some(a / b),
+            Operator::Divide => None,
+        }
+    }
+
+    // The a for which a op b = result, if there is exactly one. Multiplying
+    // by 0 gives 0 whatever a was, so that case is left to the caller.
+    pub fn undo(self, result: u64, b: u64) -> Option<u64> {
+        match self {
+            Operator::Add => result.checked_sub(b),
+            Operator::Multiply if b != 0 && result.is_multiple_of(b) => Some(result / b),
+            Operator::Multiply => None,
+            Operator::Concatenate => {
+                // If the shift doesn't fit, b has as many digits as a u64
+                // can, so result can only be b itself with a = 0
+                let shift = concat_shift(b).unwrap_or(0);
+                match result.checked_sub(b) {
+                    Some(rest) if shift == 0 => (rest == 0).then_some(0),
+                    Some(rest) if rest.is_multiple_of(shift) => Some(rest / shift),
+                    _ => None,
+                }
+            }
+            Operator::Subtract => result.checked_add(b),
+            Operator::Divide if b != 0 => result.checked_mul(b),
+            Operator::Divide => None,
+        }
+    }
+}
+
+// Parse a comma-separated list of operator symbols, e.g. "+,*,||"
+pub fn parse_operators(list: &str) -> Result<Vec<Operator>, String> {
+    let mut ops = vec![];
+    for symbol in list.split(',') {
+        match Operator::from_symbol(symbol.trim()) {
+            Some(op) if !ops.contains(&op) => ops.push(op),
+            Some(_) => {}
+            None => return Err(format!("unknown operator '{}'", symbol.trim())),
+        }
+    }
+
+    Ok(ops)
+}
+
+// Evaluate left to right, or None if any step leaves the range of a u64
+pub fn evaluate(operands: &[u64], operators: &[Operator]) -> Option<u64> {
+    evaluate_in(Mode::LeftToRight, operands, operators)
+}
+
+// A partly evaluated expression under standard precedence: a running sum,
+// the product being built to add to it, and the concatenation being built
+// to multiply that by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Partial {
+    sum: u64,
+    sum_op: Operator,
+    term: u64,
+    term_op: Operator,
+    factor: u64,
+}
+
+impl Partial {
+    pub(crate) fn new(first: u64) -> Self {
+        Partial {
+            sum: 0,
+            sum_op: Operator::Add,
+            term: 1,
+            term_op: Operator::Multiply,
+            factor: first,
+        }
+    }
+
+    pub(crate) fn push(self, op: Operator, b: u64) -> Option<Self> {
+        let mut next = self;
+        match op {
+            Operator::Concatenate => next.factor = op.apply(self.factor, b)?,
+            Operator::Multiply | Operator::Divide => {
+                next.term = self.term_op.apply(self.term, self.factor)?;
+                next.term_op = op;
+                next.factor = b;
+            }
+            Operator::Add | Operator::Subtract => {
+                next.sum = self.value()?;
+                next.sum_op = op;
+                next.term = 1;
+                next.term_op = Operator::Multiply;
+                next.factor = b;
+            }
+        }
+        Some(next)
+    }
+
+    pub(crate) fn value(self) -> Option<u64> {
+        let term = self.term_op.apply(self.term, self.factor)?;
+        self.sum_op.apply(self.sum, term)
+    }
+}
+
+pub fn evaluate_in(mode: Mode, operands: &[u64], operators: &[Operator]) -> Option<u64> {
+    assert_eq!(operators.len() + 1, operands.len());
+    let mut steps = operators.iter().zip(&operands[1..]);
+
+    match mode {
+        Mode::LeftToRight => steps.try_fold(operands[0], |acc, (op, &b)| op.apply(acc, b)),
+        Mode::Precedence => steps
+            .try_fold(Partial::new(operands[0]), |acc, (&op, &b)| acc.push(op, b))?
+            .value(),
+    }
+}
+
+// Search forwards for operators giving target under standard precedence.
+// Subtraction means no partial value can be ruled out early, so this is
+// the full search, less any assignment that leaves the u64 range.
+fn solve_precedence(
+    operands: &[u64],
+    partial: Partial,
+    target: u64,
+    ops: &[Operator],
+    chosen: &mut Vec<Operator>,
+) -> bool {
+    let Some((&b, rest)) = operands.split_first() else {
+        return partial.value() == Some(target);
+    };
+
+    for &op in ops {
+        let Some(next) = partial.push(op, b) else {
+            continue;
+        };
+        chosen.push(op);
+        if solve_precedence(rest, next, target, ops, chosen) {
+            return true;
+        }
+        chosen.pop();
+    }
+
+    false
+}
+
+// Any assignment for which operands evaluate without overflowing, trying
+// operators in order
+fn any_assignment(operands: &[u64], acc: u64, ops: &[Operator]) -> Option<Vec<Operator>> {
+    let Some((&b, rest)) = operands.split_first() else {
+        return Some(vec![]);
+    };
+
+    ops.iter().find_map(|&op| {
+        let mut chosen = any_assignment(rest, op.apply(acc, b)?, ops)?;
+        chosen.insert(0, op);
+        Some(chosen)
+    })
+}
+
+// Find operators for operands[..n] giving target, pushing them onto chosen
+// last first
+fn solve_prefix(
+    operands: &[u64],
+    target: u64,
+    ops: &[Operator],
+    chosen: &mut Vec<Operator>,
+) -> bool {
+    let Some((&last, rest)) = operands.split_last() else {
+        unreachable!("equations always have an operand");
+    };
+    if rest.is_empty() {
+        return last == target;
+    }
+
+    for &op in ops {
+        if op == Operator::Multiply && last == 0 {
+            // x * 0 is 0 for any x, so any prefix that can be evaluated
+            // without overflowing will do
+            if target != 0 {
+                continue;
+            }
+            if let Some(prefix) = any_assignment(&rest[1..], rest[0], ops) {
+                chosen.push(op);
+                chosen.extend(prefix.into_iter().rev());
+                return true;
+            }
+            continue;
+        }
+
+        let Some(previous) = op.undo(target, last) else {
+            continue;
+        };
+        chosen.push(op);
+        if solve_prefix(rest, previous, ops, chosen) {
+            return true;
+        }
+        chosen.pop();
+    }
+
+    false
+}
+
+impl Equation {
+    // Operators from ops that make the equation true, evaluated left to
+    // right with no step leaving the u64 range, or None if there aren't any
+    pub fn solve(&self, ops: &[Operator]) -> Option<Vec<Operator>> {
+        self.solve_in(Mode::LeftToRight, ops)
+    }
+
+    pub fn solve_in(&self, mode: Mode, ops: &[Operator]) -> Option<Vec<Operator>> {
+        let mut chosen = vec![];
+        match mode {
+            Mode::LeftToRight => {
+                if !solve_prefix(&self.operands, self.testval, ops, &mut chosen) {
+                    return None;
+                }
+                chosen.reverse();
+            }
+            Mode::Precedence => {
+                let partial = Partial::new(self.operands[0]);
+                let rest = &self.operands[1..];
+                if !solve_precedence(rest, partial, self.testval, ops, &mut chosen) {
+                    return None;
+                }
+            }
+        }
+
+        Some(chosen)
+    }
+
+    // The equation with the given operators filled in, e.g. "190 = 10 * 19"
+    pub fn format_with(&self, operators: &[Operator]) -> String {
+        let mut text = format!("{} = {}", self.testval, self.operands[0]);
+        for (op, b) in operators.iter().zip(&self.operands[1..]) {
+            text += &format!(" {} {}", op.symbol(), b);
+        }
+        text
+    }
+}
+
+impl fmt::Display for Equation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operands: Vec<String> = self.operands.iter().map(|n| n.to_string()).collect();
+        write!(f, "{}: {}", self.testval, operands.join(" "))
+    }
+}
+
+fn parse_equation(idx: usize, line: &str) -> Result<Equation, ParseError> {
+    let mut parts = line.split_whitespace();
+    let Some(testval) = parts.next() else {
+        return Err(ParseError::at_end(idx + 1, line, "a test value"));
+    };
+
+    let equation = Equation {
+        testval: parse_token(idx + 1, line, testval.trim_end_matches(':'), "a test value")?,
+        operands: collect_all(parts.map(|s| parse_token(idx + 1, line, s, "an operand")))?,
+    };
+    if equation.operands.is_empty() {
+        return Err(ParseError::at_end(idx + 1, line, "an operand"));
+    }
+
+    Ok(equation)
+}
+
+pub fn read_equations(reader: impl BufRead) -> Result<Vec<Equation>, ParseError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+
+    collect_all(
+        lines
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| parse_equation(idx, line)),
+    )
+}
+
+// The total of the test values of the equations solvable with ops. It's a
+// u128 as enough large test values could overflow a u64.
+pub fn sum_calibration_values(equations: &[Equation], ops: &[Operator]) -> u128 {
+    sum_calibration_values_in(Mode::LeftToRight, equations, ops)
+}
+
+pub fn sum_calibration_values_in(mode: Mode, equations: &[Equation], ops: &[Operator]) -> u128 {
+    equations
+        .iter()
+        .filter(|equation| equation.solve_in(mode, ops).is_some())
+        .map(|equation| equation.testval as u128)
+        .sum()
+}
@@ -0,0 +1,106 @@
+use aoc_common::InputSource;
+use day07_solver::count::calibration_report;
+use day07_solver::{parse_operators, read_equations, sum_calibration_values_in, Mode, Operator};
+use std::env;
+
+fn usage(program: &str) -> ! {
+    eprintln!(
+        "Usage: {} [--ops <list>] [--precedence] [--all | --count] <input>",
+        program
+    );
+    eprintln!();
+    eprintln!("<input> is a file path, - for stdin, or --input-str <text>");
+    eprintln!("<list> is comma-separated operators out of +,*,||,-,/ (default +,*,||)");
+    eprintln!("--precedence evaluates || first, then * and /, then + and -");
+    eprintln!("--all prints every solution rather than one per line");
+    eprintln!("--count prints how many solutions each line has, and which have several");
+    std::process::exit(1);
+}
+
+// What to print for each solvable line
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Listing {
+    One,
+    All,
+    Count,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let name = &args[0];
+
+    let mut ops = vec![Operator::Add, Operator::Multiply, Operator::Concatenate];
+    let mut mode = Mode::LeftToRight;
+    let mut listing = Listing::One;
+    let mut rest = &args[1..];
+    loop {
+        match rest {
+            [flag, list, tail @ ..] if flag == "--ops" => {
+                ops = parse_operators(list).unwrap_or_else(|err| {
+                    eprintln!("{}", err);
+                    usage(name);
+                });
+                rest = tail;
+            }
+            [flag, tail @ ..] if flag == "--precedence" => {
+                mode = Mode::Precedence;
+                rest = tail;
+            }
+            [flag, tail @ ..] if flag == "--all" => {
+                listing = Listing::All;
+                rest = tail;
+            }
+            [flag, tail @ ..] if flag == "--count" => {
+                listing = Listing::Count;
+                rest = tail;
+            }
+            _ => break,
+        }
+    }
+
+    let Some((source, [])) = InputSource::from_args(rest) else {
+        usage(name);
+    };
+    let equations = source
+        .reader()
+        .and_then(read_equations)
+        .map_err(|err| err.with_file(source.path()))
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+
+    match listing {
+        Listing::One => {
+            for equation in &equations {
+                if let Some(operators) = equation.solve_in(mode, &ops) {
+                    println!("{}", equation.format_with(&operators));
+                }
+            }
+        }
+        Listing::All => {
+            for equation in &equations {
+                for operators in equation.solutions(mode, &ops) {
+                    println!("{}", equation.format_with(&operators));
+                }
+            }
+        }
+        Listing::Count => {
+            let report = calibration_report(mode, &equations, &ops);
+            for &(idx, count) in &report.counts {
+                println!("{} {}", count, equations[idx]);
+            }
+            println!("total {}", report.total);
+            println!(
+                "ambiguous {} of {} solvable lines",
+                report.ambiguous().count(),
+                report.counts.len()
+            );
+            return;
+        }
+    }
+    println!(
+        "total {}",
+        sum_calibration_values_in(mode, &equations, &ops)
+    );
+}
@@ -0,0 +1,246 @@
+// The day 7 equation solver
+use aoc_testing::Lcg;
+use day07_solver::count::calibration_report;
+use day07_solver::{
+    evaluate, evaluate_in, parse_operators, read_equations, sum_calibration_values,
+    sum_calibration_values_in, Equation, Mode, Operator,
+};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+const ALL: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+
+fn example() -> Vec<Equation> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../examples/day07/example.txt");
+    read_equations(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+fn equation(testval: u64, operands: &[u64]) -> Equation {
+    Equation {
+        testval,
+        operands: operands.to_vec(),
+    }
+}
+
+#[test]
+fn solutions_report_working_operators() {
+    let equations = example();
+
+    for equation in &equations {
+        if let Some(operators) = equation.solve(&ALL) {
+            assert_eq!(
+                evaluate(&equation.operands, &operators),
+                Some(equation.testval)
+            );
+        }
+    }
+
+    let found: Vec<String> = equations
+        .iter()
+        .filter_map(|e| Some(e.format_with(&e.solve(&ALL)?)))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            "190 = 10 * 19",
+            "3267 = 81 * 40 + 27",
+            "156 = 15 || 6",
+            "7290 = 6 * 8 || 6 * 15",
+            "192 = 17 || 8 + 14",
+            "292 = 11 + 6 * 16 + 20",
+        ]
+    );
+
+    assert_eq!(
+        sum_calibration_values(&equations, &[Operator::Add, Operator::Multiply]),
+        3749
+    );
+    assert_eq!(sum_calibration_values(&equations, &ALL), 11387);
+}
+
+#[test]
+fn overflow_is_never_a_solution() {
+    // u64::MAX * 2 wraps to u64::MAX - 1, and + 1 to 0
+    assert_eq!(equation(u64::MAX - 1, &[u64::MAX, 2]).solve(&ALL), None);
+    assert_eq!(equation(0, &[u64::MAX, 1]).solve(&ALL), None);
+    assert_eq!(
+        equation(u64::MAX, &[u64::MAX - 1, 1]).solve(&ALL),
+        Some(vec![Operator::Add])
+    );
+    assert_eq!(evaluate(&[u64::MAX, 2], &[Operator::Concatenate]), None);
+
+    // Multiplying by 0 only works if what comes before it can be evaluated
+    assert_eq!(
+        equation(0, &[5, 2, 0]).solve(&ALL),
+        Some(vec![Operator::Add, Operator::Multiply])
+    );
+    assert_eq!(equation(0, &[u64::MAX, 2, 0]).solve(&ALL), None);
+    assert_eq!(
+        equation(0, &[u64::MAX, 0, 0]).solve(&ALL),
+        Some(vec![Operator::Multiply, Operator::Add])
+    );
+
+    // Concatenating numbers of up to 20 digits
+    let big = 10_000_000_000_000_000_000;
+    assert_eq!(
+        equation(big, &[0, big]).solve(&[Operator::Concatenate]),
+        Some(vec![Operator::Concatenate])
+    );
+    assert_eq!(
+        equation(big, &[1, big]).solve(&[Operator::Concatenate]),
+        None
+    );
+    assert_eq!(
+        equation(1_000_000_000_000_000_000, &[1, 0, 0, 0, 0]).solve(&[Operator::Concatenate]),
+        None
+    );
+}
+
+#[test]
+fn operator_sets_parse_from_symbols() {
+    assert_eq!(
+        parse_operators("+,*,||,-,/"),
+        Ok(vec![
+            Operator::Add,
+            Operator::Multiply,
+            Operator::Concatenate,
+            Operator::Subtract,
+            Operator::Divide
+        ])
+    );
+    assert_eq!(
+        parse_operators("+, *,+"),
+        Ok(vec![Operator::Add, Operator::Multiply])
+    );
+    assert!(parse_operators("+,%").is_err());
+}
+
+#[test]
+fn precedence_binds_concatenation_then_multiplication() {
+    use Operator::*;
+
+    assert_eq!(
+        evaluate_in(Mode::Precedence, &[2, 3, 4], &[Add, Multiply]),
+        Some(14)
+    );
+    assert_eq!(
+        evaluate_in(Mode::LeftToRight, &[2, 3, 4], &[Add, Multiply]),
+        Some(20)
+    );
+    assert_eq!(
+        evaluate_in(Mode::Precedence, &[1, 2, 3], &[Concatenate, Multiply]),
+        Some(36)
+    );
+    assert_eq!(
+        evaluate_in(Mode::Precedence, &[3, 1, 2], &[Multiply, Concatenate]),
+        Some(36)
+    );
+    assert_eq!(
+        evaluate_in(Mode::Precedence, &[10, 2, 3], &[Subtract, Multiply]),
+        Some(4)
+    );
+    assert_eq!(
+        evaluate_in(Mode::Precedence, &[12, 3, 2], &[Divide, Divide]),
+        Some(2)
+    );
+    // Below zero part way through, and a remainder
+    assert_eq!(
+        evaluate_in(Mode::Precedence, &[1, 5, 10], &[Subtract, Add]),
+        None
+    );
+    assert_eq!(evaluate_in(Mode::Precedence, &[7, 2], &[Divide]), None);
+
+    let ops = [Add, Multiply];
+    assert_eq!(
+        sum_calibration_values_in(Mode::Precedence, &example(), &ops),
+        3457
+    );
+}
+
+// Every assignment of ops, for checking the solvers against
+fn assignments(n: usize, ops: &[Operator]) -> Vec<Vec<Operator>> {
+    (0..n).fold(vec![vec![]], |partial, _| {
+        partial
+            .iter()
+            .flat_map(|prefix| {
+                ops.iter().map(move |&op| {
+                    let mut next = prefix.clone();
+                    next.push(op);
+                    next
+                })
+            })
+            .collect()
+    })
+}
+
+#[test]
+fn solvers_agree_with_trying_everything() {
+    let ops = parse_operators("+,*,||,-,/").unwrap();
+    let mut rng = Lcg::new(7);
+
+    for _ in 0..300 {
+        let operands: Vec<u64> = (0..rng.below(4) + 2).map(|_| rng.below(12)).collect();
+        let all = assignments(operands.len() - 1, &ops);
+
+        for mode in [Mode::LeftToRight, Mode::Precedence] {
+            // Aim for a reachable value most of the time
+            let pick = &all[rng.below(all.len() as u64) as usize];
+            let testval = evaluate_in(mode, &operands, pick).unwrap_or(rng.below(100));
+            let equation = Equation {
+                testval,
+                operands: operands.clone(),
+            };
+
+            let expected: Vec<&Vec<Operator>> = all
+                .iter()
+                .filter(|ops| evaluate_in(mode, &operands, ops) == Some(testval))
+                .collect();
+            let exists = !expected.is_empty();
+
+            // Counting and listing find exactly the same assignments, in the
+            // same order as trying everything
+            assert_eq!(equation.count_solutions(mode, &ops), expected.len() as u128);
+            let listed: Vec<Vec<Operator>> = equation.solutions(mode, &ops).collect();
+            assert_eq!(listed.iter().collect::<Vec<_>>(), expected);
+
+            match equation.solve_in(mode, &ops) {
+                Some(found) => assert_eq!(
+                    evaluate_in(mode, &operands, &found),
+                    Some(testval),
+                    "{:?}",
+                    equation
+                ),
+                None => assert!(!exists, "{:?} {:?}", mode, equation),
+            }
+        }
+    }
+}
+
+#[test]
+fn reports_count_solutions_and_flag_ambiguous_lines() {
+    let equations = example();
+    let ops = [Operator::Add, Operator::Multiply];
+
+    let report = calibration_report(Mode::LeftToRight, &equations, &ops);
+    assert_eq!(report.total, 3749);
+    assert_eq!(report.counts, vec![(0, 1), (1, 2), (8, 1)]);
+    assert_eq!(report.ambiguous().collect::<Vec<_>>(), vec![(1, 2)]);
+
+    // Lazily: only the first solution is ever worked out here
+    let mut solutions = equations[1].solutions(Mode::LeftToRight, &ops);
+    assert_eq!(
+        solutions.next(),
+        Some(vec![Operator::Add, Operator::Multiply])
+    );
+
+    // 0 * anything, so every assignment of the rest works
+    let zeros = Equation {
+        testval: 0,
+        operands: vec![0; 30],
+    };
+    assert_eq!(
+        zeros.count_solutions(Mode::LeftToRight, &ALL),
+        3_u128.pow(29)
+    );
+}
@@ -0,0 +1,121 @@
+use aoc_common::{read_grid, Grid, ParseError, Point, Solution, SolveError};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Antenna {
+    frequency: char,
+    position: Point,
+}
+
+fn read_antennas(reader: impl BufRead) -> Result<(Vec<Antenna>, usize, usize), ParseError> {
+    let grid: Grid<char> = read_grid(reader)?;
+    let mut antennas = vec![];
+
+    for row in 0..grid.rows() {
+        for col in 0..grid.cols() {
+            if grid[(row, col)] != '.' {
+                let antenna = Antenna {
+                    frequency: grid[(row, col)],
+                    position: Point::from((row, col)),
+                };
+                antennas.push(antenna);
+            }
+        }
+    }
+
+    Ok((antennas, grid.rows(), grid.cols()))
+}
+
+// Find all antenna pairs
+fn get_antenna_pairs(antennas: Vec<Antenna>) -> Vec<(Antenna, Antenna)> {
+    let mut result = vec![];
+
+    // get list of frequencies
+    let frequencies: HashSet<_> = antennas.iter().map(|x| x.frequency).collect();
+
+    // for each frequency
+    for frequency in frequencies {
+        // get all antennas with that frequency
+        let antenna_list: Vec<_> = antennas
+            .iter()
+            .filter(|x| x.frequency == frequency)
+            .collect();
+
+        // cartesian set of all of those
+        let antenna_pairs: Vec<(Antenna, Antenna)> = antenna_list
+            .iter()
+            .cloned()
+            .flat_map(|a| {
+                antenna_list
+                    .iter()
+                    .cloned()
+                    .filter(move |b| a != *b)
+                    .map(move |b| (a.clone(), b.clone()))
+            })
+            .collect();
+
+        // push to list
+        for pair in antenna_pairs {
+            result.push(pair);
+        }
+    }
+
+    result
+}
+
+// Find antinodes for each antenna pair
+fn calc_antinodes(pair: (Antenna, Antenna)) -> (Point, Point) {
+    let (a, b) = pair;
+    let offset = a.position - b.position;
+
+    let antinode1 = a.position + offset;
+    let antinode2 = b.position - offset;
+
+    /*    println!("Antinodes:");
+    println!("{:#?}, {:#?}: {:#?}, {:#?}", a, b, antinode1, antinode2);
+    println!("");*/
+
+    (antinode1, antinode2)
+}
+
+fn in_bounds(antinode: Point, rows: usize, cols: usize) -> bool {
+    match antinode.to_index() {
+        Some((row, col)) => row < rows && col < cols,
+        None => false,
+    }
+}
+
+// Count unique antinodes
+fn count_unique_antinodes(antennas: Vec<Antenna>, rows: usize, cols: usize) -> usize {
+    let antenna_pairs = get_antenna_pairs(antennas);
+    let mut all_antinodes = HashSet::new();
+
+    for pair in antenna_pairs {
+        let antinodes = calc_antinodes(pair);
+        if in_bounds(antinodes.0, rows, cols) {
+            all_antinodes.insert(antinodes.0);
+        }
+        if in_bounds(antinodes.1, rows, cols) {
+            all_antinodes.insert(antinodes.1);
+        }
+    }
+
+    all_antinodes.len()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Vec<Antenna>, usize, usize);
+    const DAY: u8 = 8;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_antennas(reader)
+    }
+
+    fn part1((antennas, rows, cols): Self::Input) -> Result<Option<String>, SolveError> {
+        let count = count_unique_antinodes(antennas, rows, cols);
+        Ok(Some(count.to_string()))
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use day09_disk::{checksum, compact, expand_diskmap, read_diskmap, Strategy};
+use std::io::BufRead;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<usize>;
+    const DAY: u8 = 9;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_diskmap(reader)
+    }
+
+    fn part2(diskmap: Self::Input) -> Result<Option<String>, SolveError> {
+        let disk = expand_diskmap(&diskmap);
+        let compacted_disk = compact(&disk, Strategy::Files);
+
+        Ok(Some(checksum(&compacted_disk).to_string()))
+    }
+}
@@ -0,0 +1,300 @@
+// Day 9 disk compaction, shared by both parts. A disk is a list of spans,
+// each a run of blocks belonging to one file or free.
+//
+// Free spans are indexed in a min-heap of start positions per size, so
+// finding the leftmost span that can take a file means looking at the top of
+// at most nine heaps rather than scanning the disk. No file in a diskmap is
+// longer than nine blocks (one digit), so spans of nine or more share the
+// last heap. They are also kept by length and then start, for finding the
+// shortest or longest of them, or ones that fit a longer file on a disk built
+// by hand: that's one range query per length, and k different lengths of
+// nine or more take up at least k * k / 2 blocks, so there are never many.
+// A file moving into a span leaves the rest of it free as a shorter one, and
+// heap entries for spans that have since changed are skipped when they come
+// to the top.
+//
+// The policy module has other ways of choosing which file moves where.
+use aoc_common::{collect_all, ParseError};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use std::io::BufRead;
+
+pub mod policy;
+
+use policy::{Fit, Policy};
+
+// A run of blocks: the file id they belong to, or None if free, and how many
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Block(pub Option<usize>, pub usize);
+
+// How files are moved left into free space
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Strategy {
+    // A block at a time, splitting files up (part 1)
+    #[default]
+    Blocks,
+    // Only whole files, each tried once (part 2)
+    Files,
+}
+
+pub fn read_diskmap(mut reader: impl BufRead) -> Result<Vec<usize>, ParseError> {
+    let mut diskmap_str = String::new();
+    reader.read_line(&mut diskmap_str)?;
+
+    let line = diskmap_str.trim_end();
+    let digits = line.trim_start();
+    let diskmap: Vec<usize> =
+        collect_all(digits.char_indices().map(|(i, x)| match x.to_digit(10) {
+            Some(d) => Ok(d as usize),
+            None => Err(ParseError::at_token(1, line, &digits[i..], "a digit")),
+        }))?;
+
+    Ok(diskmap)
+}
+
+// The diskmap's alternating file and free sizes as spans, with file ids
+// counting up from 0
+pub fn expand_diskmap(diskmap: &[usize]) -> Vec<Block> {
+    let mut file_id = 0;
+    let mut disk = vec![];
+
+    let mut iter = diskmap.iter();
+    while let (Some(&filesz), freesz) = (iter.next(), iter.next()) {
+        let freesz = freesz.unwrap_or(&0);
+        disk.push(Block(Some(file_id), filesz));
+        disk.push(Block(None, *freesz));
+
+        file_id += 1;
+    }
+
+    disk
+}
+
+// Where a file (or part of one) is: its id, first block and length
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Extent {
+    id: usize,
+    start: usize,
+    len: usize,
+}
+
+// The free spans on a disk, by start position
+#[derive(Debug, Default)]
+struct FreeSpans {
+    lens: BTreeMap<usize, usize>,
+    // The start of every span of each length, smallest first, with the last
+    // heap holding every span of nine or more
+    heaps: [BinaryHeap<Reverse<usize>>; 10],
+    // The starts of every span of each length of nine or more
+    long: BTreeMap<usize, BTreeSet<usize>>,
+}
+
+fn heap_for(len: usize) -> usize {
+    len.min(9)
+}
+
+impl FreeSpans {
+    fn insert(&mut self, start: usize, len: usize) {
+        if len > 0 {
+            self.lens.insert(start, len);
+            self.heaps[heap_for(len)].push(Reverse(start));
+            if len >= 9 {
+                self.long.entry(len).or_default().insert(start);
+            }
+        }
+    }
+
+    fn remove(&mut self, start: usize) -> Option<usize> {
+        let len = self.lens.remove(&start)?;
+        if let Some(starts) = self.long.get_mut(&len) {
+            starts.remove(&start);
+            if starts.is_empty() {
+                self.long.remove(&len);
+            }
+        }
+        Some(len)
+    }
+
+    // The leftmost span in a heap, dropping entries for spans that are gone
+    fn first_in(&mut self, heap: usize) -> Option<(usize, usize)> {
+        while let Some(&Reverse(start)) = self.heaps[heap].peek() {
+            if let Some(&len) = self.lens.get(&start).filter(|&&len| heap_for(len) == heap) {
+                return Some((start, len));
+            }
+            self.heaps[heap].pop();
+        }
+        None
+    }
+
+    // The leftmost span of each of lengths that starts before limit
+    fn long_before<'a>(
+        mut lengths: impl Iterator<Item = (&'a usize, &'a BTreeSet<usize>)>,
+        limit: usize,
+    ) -> Option<(usize, usize)> {
+        lengths.find_map(|(&len, starts)| Some((*starts.range(..limit).next()?, len)))
+    }
+
+    // The span at least len long starting before limit that fit picks, the
+    // leftmost of its length if there's more than one
+    fn fitting(&mut self, len: usize, limit: usize, fit: Fit) -> Option<(usize, usize)> {
+        let before_limit = |&(start, _): &(usize, usize)| start < limit;
+        // Heaps for spans of exactly one length
+        let exact = heap_for(len.max(1))..9;
+
+        match fit {
+            Fit::First => {
+                let mut leftmost: Vec<_> = exact.filter_map(|heap| self.first_in(heap)).collect();
+                // Only a file longer than nine blocks can be too long for the
+                // last heap's leftmost span
+                if len <= 9 {
+                    leftmost.extend(self.first_in(9));
+                } else {
+                    let firsts = self.long.range(len..);
+                    leftmost
+                        .extend(firsts.filter_map(|(&len, starts)| Some((*starts.first()?, len))));
+                }
+                leftmost.into_iter().filter(before_limit).min()
+            }
+            Fit::Best => {
+                for heap in exact {
+                    if let Some(span) = self.first_in(heap).filter(before_limit) {
+                        return Some(span);
+                    }
+                }
+                Self::long_before(self.long.range(len.max(9)..), limit)
+            }
+            Fit::Worst => {
+                if let Some(span) = Self::long_before(self.long.range(len.max(9)..).rev(), limit) {
+                    return Some(span);
+                }
+                exact
+                    .rev()
+                    .find_map(|heap| self.first_in(heap).filter(before_limit))
+            }
+        }
+    }
+
+    // The leftmost span at least len long starting before limit
+    fn leftmost(&mut self, len: usize, limit: usize) -> Option<(usize, usize)> {
+        self.fitting(len, limit, Fit::First)
+    }
+
+    // Fill the first used blocks of the span at start, keeping the rest free
+    fn take(&mut self, start: usize, used: usize) {
+        let len = self.remove(start).expect("taking a free span");
+        self.insert(start + used, len - used);
+    }
+
+    // Free the blocks a file has moved out of, joining them to any free span
+    // on either side
+    fn release(&mut self, mut start: usize, mut len: usize) {
+        if let Some((&prev, &prev_len)) = self.lens.range(..start).next_back() {
+            if prev + prev_len == start {
+                self.remove(prev);
+                start = prev;
+                len += prev_len;
+            }
+        }
+        if let Some(next_len) = self.remove(start + len) {
+            len += next_len;
+        }
+        self.insert(start, len);
+    }
+}
+
+// Where every file is, and the free spans between them. Free spans either
+// side of an empty file are one span, as nothing separates them.
+fn extents(disk: &[Block]) -> (Vec<Extent>, FreeSpans) {
+    let mut files = vec![];
+    let mut free = FreeSpans::default();
+    let mut start = 0;
+    let mut free_start = 0;
+
+    for &Block(id, len) in disk {
+        if let Some(id) = id.filter(|_| len > 0) {
+            free.insert(free_start, start - free_start);
+            files.push(Extent { id, start, len });
+            free_start = start + len;
+        }
+        start += len;
+    }
+    free.insert(free_start, start - free_start);
+
+    (files, free)
+}
+
+// Lay extents out as spans again, with free spans filling the gaps between
+// them
+fn to_blocks(mut placed: Vec<Extent>, disk_len: usize) -> Vec<Block> {
+    placed.sort_by_key(|extent| extent.start);
+    let mut disk = vec![];
+    let mut pos = 0;
+
+    for extent in placed {
+        if extent.start > pos {
+            disk.push(Block(None, extent.start - pos));
+        }
+        disk.push(Block(Some(extent.id), extent.len));
+        pos = extent.start + extent.len;
+    }
+    if disk_len > pos {
+        disk.push(Block(None, disk_len - pos));
+    }
+
+    disk
+}
+
+fn disk_len(disk: &[Block]) -> usize {
+    disk.iter().map(|block| block.1).sum()
+}
+
+// Move files left into free space, last file first
+pub fn compact(disk: &[Block], strategy: Strategy) -> Vec<Block> {
+    match strategy {
+        Strategy::Blocks => compact_blocks(disk),
+        Strategy::Files => policy::compact_with(disk, Policy::default()),
+    }
+}
+
+fn compact_blocks(disk: &[Block]) -> Vec<Block> {
+    let (mut files, mut free) = extents(disk);
+
+    let mut placed = vec![];
+    while let Some(mut file) = files.pop() {
+        // Fill the leftmost free blocks from the end of the file
+        while file.len > 0 {
+            let Some((start, len)) = free.leftmost(1, file.start) else {
+                break;
+            };
+            let moved = len.min(file.len);
+            free.take(start, moved);
+            placed.push(Extent {
+                id: file.id,
+                start,
+                len: moved,
+            });
+            file.len -= moved;
+        }
+        if file.len > 0 {
+            placed.push(file);
+        }
+    }
+
+    to_blocks(placed, disk_len(disk))
+}
+
+pub fn checksum(disk: &[Block]) -> u64 {
+    let mut result = 0;
+    let mut block_position = 0;
+
+    for &Block(id, len) in disk {
+        if let Some(id) = id {
+            // id * (position + ... + position + len - 1)
+            let positions = len * block_position + len * len.saturating_sub(1) / 2;
+            result += (id * positions) as u64;
+        }
+        block_position += len;
+    }
+
+    result
+}
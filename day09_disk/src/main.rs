@@ -0,0 +1,47 @@
+use aoc_common::InputSource;
+use day09_disk::policy::{compact_with, Policy, Report};
+use day09_disk::{compact, expand_diskmap, read_diskmap, Strategy};
+use std::env;
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} <input>", program);
+    eprintln!();
+    eprintln!("<input> is a file path, - for stdin, or --input-str <text>");
+    eprintln!("Compacts the disk under every policy and compares how each turns out");
+    std::process::exit(1);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let name = &args[0];
+
+    let Some((source, [])) = InputSource::from_args(&args[1..]) else {
+        usage(name);
+    };
+    let diskmap = source
+        .reader()
+        .and_then(read_diskmap)
+        .map_err(|err| err.with_file(source.path()))
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        });
+    let disk = expand_diskmap(&diskmap);
+
+    let mut results = vec![("blocks".to_string(), compact(&disk, Strategy::Blocks))];
+    for policy in Policy::ALL {
+        results.push((policy.to_string(), compact_with(&disk, policy)));
+    }
+
+    println!(
+        "{:<22} {:>16} {:>6} {:>6} {:>8}",
+        "policy", "checksum", "moved", "gaps", "largest"
+    );
+    for (policy, compacted) in results {
+        let report = Report::new(&disk, &compacted);
+        println!(
+            "{:<22} {:>16} {:>6} {:>6} {:>8}",
+            policy, report.checksum, report.files_moved, report.free_gaps, report.largest_free_span
+        );
+    }
+}
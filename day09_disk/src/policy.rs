@@ -0,0 +1,143 @@
+// Whole-file compaction under other policies than the puzzle's, for
+// comparing how tightly each packs the same disk. A policy picks which free
+// span a file moves into (the leftmost, the shortest or the longest that
+// fits) and which order files are tried in. Each file is still tried once,
+// and only ever moves left.
+//
+// Moving files lowest id first means later files can use the space earlier
+// ones left behind, so that space is freed again as each file moves. Among
+// spans of the same length, the leftmost is taken.
+use crate::{checksum, disk_len, extents, to_blocks, Block};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Fit {
+    // The leftmost span the file fits in
+    #[default]
+    First,
+    // The shortest
+    Best,
+    // The longest
+    Worst,
+}
+
+// Which file is tried first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Order {
+    #[default]
+    Descending,
+    Ascending,
+}
+
+// The puzzle's part 2 is the default: first fit, highest id first
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Policy {
+    pub fit: Fit,
+    pub order: Order,
+}
+
+impl Policy {
+    pub const ALL: [Policy; 6] = [
+        Policy::new(Fit::First, Order::Descending),
+        Policy::new(Fit::Best, Order::Descending),
+        Policy::new(Fit::Worst, Order::Descending),
+        Policy::new(Fit::First, Order::Ascending),
+        Policy::new(Fit::Best, Order::Ascending),
+        Policy::new(Fit::Worst, Order::Ascending),
+    ];
+
+    pub const fn new(fit: Fit, order: Order) -> Self {
+        Policy { fit, order }
+    }
+}
+
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fit = match self.fit {
+            Fit::First => "first",
+            Fit::Best => "best",
+            Fit::Worst => "worst",
+        };
+        let order = match self.order {
+            Order::Descending => "descending",
+            Order::Ascending => "ascending",
+        };
+        write!(f, "{}-fit {}", fit, order)
+    }
+}
+
+// Move whole files left into free space according to policy
+pub fn compact_with(disk: &[Block], policy: Policy) -> Vec<Block> {
+    let (mut files, mut free) = extents(disk);
+    files.sort_by_key(|file| file.id);
+    if policy.order == Order::Descending {
+        files.reverse();
+    }
+
+    for file in &mut files {
+        if let Some((start, _)) = free.fitting(file.len, file.start, policy.fit) {
+            free.take(start, file.len);
+            free.release(file.start, file.len);
+            file.start = start;
+        }
+    }
+
+    to_blocks(files, disk_len(disk))
+}
+
+// Where each file's blocks are, as (start, length) runs
+fn places(disk: &[Block]) -> HashMap<usize, Vec<(usize, usize)>> {
+    let mut places: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    let mut start = 0;
+    for &Block(id, len) in disk {
+        if let Some(id) = id.filter(|_| len > 0) {
+            places.entry(id).or_default().push((start, len));
+        }
+        start += len;
+    }
+    places
+}
+
+// How a compaction went
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Report {
+    pub checksum: u64,
+    // Files not left exactly where they were
+    pub files_moved: usize,
+    // Free spans with a file somewhere after them
+    pub free_gaps: usize,
+    // The most free blocks in a row, including any at the end of the disk
+    pub largest_free_span: usize,
+}
+
+impl Report {
+    pub fn new(before: &[Block], after: &[Block]) -> Self {
+        let before_places = places(before);
+        let after_places = places(after);
+
+        // Runs of free blocks, joined across empty spans
+        let mut runs = vec![];
+        let mut free_run = 0;
+        for &Block(id, len) in after.iter().filter(|block| block.1 > 0) {
+            match id {
+                None => free_run += len,
+                Some(_) if free_run > 0 => {
+                    runs.push(free_run);
+                    free_run = 0;
+                }
+                Some(_) => {}
+            }
+        }
+
+        Report {
+            checksum: checksum(after),
+            files_moved: before_places
+                .iter()
+                .filter(|&(id, was)| after_places.get(id) != Some(was))
+                .count(),
+            free_gaps: runs.len(),
+            largest_free_span: runs.into_iter().max().unwrap_or(0).max(free_run),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+// The day 9 compaction engine
+use aoc_testing::Lcg;
+use day09_disk::policy::{compact_with, Fit, Order, Policy, Report};
+use day09_disk::{checksum, compact, expand_diskmap, Block, Strategy};
+use std::time::{Duration, Instant};
+
+// One entry per block, as the puzzle draws them
+fn blocks(disk: &[Block]) -> Vec<Option<usize>> {
+    disk.iter()
+        .flat_map(|&Block(id, len)| std::iter::repeat_n(id, len))
+        .collect()
+}
+
+// Move the last file block into the first free block until there are no gaps
+fn compact_blocks_slowly(mut disk: Vec<Option<usize>>) -> Vec<Option<usize>> {
+    while let Some(free) = disk.iter().position(|block| block.is_none()) {
+        let last = disk.iter().rposition(|block| block.is_some()).unwrap_or(0);
+        if last < free {
+            break;
+        }
+        disk.swap(free, last);
+    }
+    disk
+}
+
+// Try each file once, highest id first, in the first gap left of it
+fn compact_files_slowly(mut disk: Vec<Option<usize>>) -> Vec<Option<usize>> {
+    let max_id = disk.iter().flatten().max().copied().unwrap_or(0);
+    for id in (0..=max_id).rev() {
+        let Some(start) = disk.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let len = disk.iter().filter(|&&block| block == Some(id)).count();
+        let gap = (0..start).find(|&pos| disk[pos..pos + len].iter().all(|b| b.is_none()));
+        if let Some(gap) = gap {
+            disk[gap..gap + len].fill(Some(id));
+            disk[start..start + len].fill(None);
+        }
+    }
+    disk
+}
+
+fn disk_len(disk: &[Block]) -> usize {
+    disk.iter().map(|block| block.1).sum()
+}
+
+fn block_checksum(disk: &[Option<usize>]) -> u64 {
+    disk.iter()
+        .enumerate()
+        .map(|(pos, block)| (pos * block.unwrap_or(0)) as u64)
+        .sum()
+}
+
+#[test]
+fn example_compacts_both_ways() {
+    let disk = expand_diskmap(&[2, 3, 3, 3, 1, 3, 3, 1, 2, 1, 4, 1, 4, 1, 3, 1, 4, 0, 2]);
+
+    let by_blocks = compact(&disk, Strategy::Blocks);
+    assert_eq!(checksum(&by_blocks), 1928);
+    let drawn: String = blocks(&by_blocks)
+        .iter()
+        .map(|block| block.map_or('.', |id| char::from_digit(id as u32, 10).unwrap()))
+        .collect();
+    assert_eq!(drawn, "0099811188827773336446555566..............");
+
+    let by_files = compact(&disk, Strategy::Files);
+    assert_eq!(checksum(&by_files), 2858);
+    let drawn: String = blocks(&by_files)
+        .iter()
+        .map(|block| block.map_or('.', |id| char::from_digit(id as u32, 10).unwrap()))
+        .collect();
+    assert_eq!(drawn, "00992111777.44.333....5555.6666.....8888..");
+}
+
+#[test]
+fn compaction_matches_moving_blocks_one_at_a_time() {
+    let mut rng = Lcg::new(9);
+
+    for _ in 0..300 {
+        let len = rng.below(40) as usize + 1;
+        let diskmap: Vec<usize> = (0..len).map(|_| rng.below(10) as usize).collect();
+        let disk = expand_diskmap(&diskmap);
+
+        let expected = compact_blocks_slowly(blocks(&disk));
+        let by_blocks = compact(&disk, Strategy::Blocks);
+        assert_eq!(blocks(&by_blocks), expected, "{:?}", diskmap);
+        assert_eq!(checksum(&by_blocks), block_checksum(&expected));
+
+        let expected = compact_files_slowly(blocks(&disk));
+        let by_files = compact(&disk, Strategy::Files);
+        assert_eq!(blocks(&by_files), expected, "{:?}", diskmap);
+        assert_eq!(checksum(&by_files), block_checksum(&expected));
+    }
+}
+
+// Move each file once, in the policy's order, scanning for the runs of free
+// blocks left of it.
+fn compact_with_slowly(mut disk: Vec<Option<usize>>, policy: Policy) -> Vec<Option<usize>> {
+    let mut ids: Vec<usize> = disk.iter().flatten().copied().collect();
+    ids.dedup();
+    ids.sort();
+    if policy.order == Order::Descending {
+        ids.reverse();
+    }
+
+    for id in ids {
+        let start = disk.iter().position(|&block| block == Some(id)).unwrap();
+        let len = disk.iter().filter(|&&block| block == Some(id)).count();
+
+        // (length, start) of each free run ending before the file
+        let mut runs = vec![];
+        let mut pos = 0;
+        while pos < start {
+            let run = disk[pos..start].iter().take_while(|b| b.is_none()).count();
+            if run >= len {
+                runs.push((run, pos));
+            }
+            pos += run.max(1);
+        }
+
+        let chosen = match policy.fit {
+            Fit::First => runs.first(),
+            Fit::Best => runs.iter().min_by_key(|&&(run, pos)| (run, pos)),
+            Fit::Worst => runs
+                .iter()
+                .min_by_key(|&&(run, pos)| (usize::MAX - run, pos)),
+        };
+        if let Some(&(_, gap)) = chosen {
+            disk[start..start + len].fill(None);
+            disk[gap..gap + len].fill(Some(id));
+        }
+    }
+    disk
+}
+
+#[test]
+fn policies_match_moving_blocks_one_at_a_time() {
+    let mut rng = Lcg::new(25);
+
+    for _ in 0..300 {
+        let len = rng.below(40) as usize + 1;
+        let diskmap: Vec<usize> = (0..len).map(|_| rng.below(10) as usize).collect();
+        let disk = expand_diskmap(&diskmap);
+
+        for policy in Policy::ALL {
+            let expected = compact_with_slowly(blocks(&disk), policy);
+            let compacted = compact_with(&disk, policy);
+            assert_eq!(blocks(&compacted), expected, "{} {:?}", policy, diskmap);
+        }
+        assert_eq!(
+            compact_with(&disk, Policy::default()),
+            compact(&disk, Strategy::Files)
+        );
+    }
+}
+
+// Disks built by hand can have files longer than a diskmap digit allows
+#[test]
+fn long_files_compact_like_short_ones() {
+    let mut rng = Lcg::new(24);
+
+    for _ in 0..300 {
+        let files = rng.below(12) as usize + 1;
+        let disk: Vec<Block> = (0..files)
+            .flat_map(|id| {
+                let file = Block(Some(id), rng.below(20) as usize);
+                [file, Block(None, rng.below(20) as usize)]
+            })
+            .collect();
+
+        let expected = compact_blocks_slowly(blocks(&disk));
+        let by_blocks = compact(&disk, Strategy::Blocks);
+        assert_eq!(blocks(&by_blocks), expected, "{:?}", disk);
+        for policy in Policy::ALL {
+            let expected = compact_with_slowly(blocks(&disk), policy);
+            let compacted = compact_with(&disk, policy);
+            assert_eq!(blocks(&compacted), expected, "{} {:?}", policy, disk);
+        }
+    }
+}
+
+// Every file has long free spans to the right of it, which a search that
+// passes over them would make quadratic. Finishing in seconds in a debug
+// build means it doesn't.
+#[test]
+fn compaction_scales_past_long_free_spans() {
+    let mut rng = Lcg::new(240);
+    let disk: Vec<Block> = (0..20_000)
+        .flat_map(|id| {
+            let file = Block(Some(id), rng.below(3) as usize + 1);
+            [file, Block(None, rng.below(12) as usize + 9)]
+        })
+        .collect();
+
+    let start = Instant::now();
+    let by_blocks = compact(&disk, Strategy::Blocks);
+    assert_eq!(disk_len(&by_blocks), disk_len(&disk));
+    for policy in Policy::ALL {
+        let compacted = compact_with(&disk, policy);
+        assert_eq!(disk_len(&compacted), disk_len(&disk), "{}", policy);
+    }
+    let elapsed = start.elapsed();
+    assert!(elapsed < Duration::from_secs(15), "took {:?}", elapsed);
+}
+
+#[test]
+fn reports_describe_how_compaction_went() {
+    let disk = expand_diskmap(&[2, 3, 3, 3, 1, 3, 3, 1, 2, 1, 4, 1, 4, 1, 3, 1, 4, 0, 2]);
+
+    let untouched = Report::new(&disk, &disk);
+    assert_eq!(untouched.files_moved, 0);
+    assert_eq!(untouched.free_gaps, 8);
+    assert_eq!(untouched.largest_free_span, 3);
+
+    // 00...111...2...333.44.5555.6666.777.888899 becomes
+    // 00992111777.44.333....5555.6666.....8888..
+    let report = Report::new(&disk, &compact(&disk, Strategy::Files));
+    assert_eq!(report.checksum, 2858);
+    assert_eq!(report.files_moved, 4);
+    assert_eq!(report.free_gaps, 5);
+    assert_eq!(report.largest_free_span, 5);
+
+    // Split files count as moved, and the free space all ends up at the end
+    let report = Report::new(&disk, &compact(&disk, Strategy::Blocks));
+    assert_eq!(report.files_moved, 4);
+    assert_eq!(report.free_gaps, 0);
+    assert_eq!(report.largest_free_span, 14);
+}
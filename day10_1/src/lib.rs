@@ -0,0 +1,57 @@
+use aoc_common::{read_digit_grid, Grid, ParseError, Point, Solution, SolveError};
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+fn find_trailheads(grid: &Grid<u8>) -> Vec<Point> {
+    grid.points().filter(|&p| grid[p] == 0).collect()
+}
+
+// find all in-bounds neighbors w/ value one more than point
+fn eligible_neighbors(grid: &Grid<u8>, point: Point) -> Vec<Point> {
+    grid.neighbors4(point)
+        // Check for gradual increase
+        .filter(|&n| grid[n] == grid[point] + 1)
+        .collect()
+}
+
+fn trailhead_score(grid: &Grid<u8>, trailhead: Point) -> usize {
+    let mut q: VecDeque<Point> = VecDeque::new();
+    q.push_back(trailhead);
+
+    let mut summits = HashSet::new();
+
+    while let Some(item) = q.pop_front() {
+        if grid[item] == 9 {
+            // Reached summit
+            summits.insert(item);
+        } else {
+            // check neighbors, add to queue
+            for neighbor in eligible_neighbors(grid, item) {
+                q.push_back(neighbor);
+            }
+        }
+    }
+
+    summits.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+    const DAY: u8 = 10;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_digit_grid(reader)
+    }
+
+    fn part1(grid: Self::Input) -> Result<Option<String>, SolveError> {
+        let trailheads = find_trailheads(&grid);
+        let scores: Vec<usize> = trailheads
+            .iter()
+            .map(|th| trailhead_score(&grid, *th))
+            .collect();
+
+        Ok(Some(scores.iter().sum::<usize>().to_string()))
+    }
+}
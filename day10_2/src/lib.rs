@@ -0,0 +1,64 @@
+use aoc_common::{read_digit_grid, Grid, ParseError, Point, Solution, SolveError};
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+fn find_trailheads(grid: &Grid<u8>) -> Vec<Point> {
+    grid.points().filter(|&p| grid[p] == 0).collect()
+}
+
+// find all in-bounds neighbors w/ value one more than point
+fn eligible_neighbors(grid: &Grid<u8>, point: Point) -> Vec<Point> {
+    grid.neighbors4(point)
+        // Check for gradual increase
+        .filter(|&n| grid[n] == grid[point] + 1)
+        .collect()
+}
+
+fn trailhead_rating(grid: &Grid<u8>, trailhead: Point) -> usize {
+    let mut q: VecDeque<Point> = VecDeque::new();
+    q.push_back(trailhead);
+
+    let mut rating = 1;
+
+    while let Some(item) = q.pop_front() {
+        // check neighbors, add to queue
+        let en = eligible_neighbors(grid, item);
+
+        // If we branch, it's a new trail
+        if en.len() > 1 {
+            rating += en.len() - 1;
+        }
+
+        // If we've run out without reaching a summit, this was an invalid branch
+        if en.is_empty() && grid[item] != 9 {
+            rating -= 1;
+        }
+
+        for neighbor in en {
+            q.push_back(neighbor);
+        }
+    }
+
+    rating
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+    const DAY: u8 = 10;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_digit_grid(reader)
+    }
+
+    fn part2(grid: Self::Input) -> Result<Option<String>, SolveError> {
+        let trailheads = find_trailheads(&grid);
+        let ratings: Vec<usize> = trailheads
+            .iter()
+            .map(|th| trailhead_rating(&grid, *th))
+            .collect();
+
+        Ok(Some(ratings.iter().sum::<usize>().to_string()))
+    }
+}
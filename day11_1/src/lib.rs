@@ -0,0 +1,67 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::io::BufRead;
+
+fn read_list(mut reader: impl BufRead) -> Result<Vec<u64>, ParseError> {
+    let mut stones_str = String::new();
+
+    reader.read_line(&mut stones_str)?;
+
+    let line = stones_str.trim_end();
+    let stones_strs: Vec<&str> = line.trim_start().split(" ").collect::<Vec<&str>>();
+    let stones = collect_all(stones_strs.iter().map(|x| parse_token(1, line, x, "a number")))?;
+
+    Ok(stones)
+}
+
+fn split_stone(stone: u64) -> Vec<u64> {
+    let s: String = stone.to_string();
+    let (s1, s2) = s.split_at(s.len() / 2);
+
+    vec![s1.parse().unwrap(), s2.parse().unwrap()]
+}
+
+fn even_digits(stone: u64) -> bool {
+    let s: String = stone.to_string();
+    s.len().is_multiple_of(2)
+}
+
+fn change_stone(stone: u64) -> Vec<u64> {
+    if stone == 0 {
+        // Rule 1: replace 0 with 1
+        vec![1]
+    } else if even_digits(stone) {
+        // Rule 2: even digits split into two stones
+        split_stone(stone)
+    } else {
+        // Rule 3: Multiply by 2024
+        vec![stone * 2024]
+    }
+}
+
+fn change_stones(stones: Vec<u64>) -> Vec<u64> {
+    let mut result = vec![];
+    for stone in stones {
+        result.extend(change_stone(stone));
+    }
+
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    const DAY: u8 = 11;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_list(reader)
+    }
+
+    fn part1(mut stones: Self::Input) -> Result<Option<String>, SolveError> {
+        for _ in 0..25 {
+            stones = change_stones(stones);
+        }
+
+        Ok(Some(stones.len().to_string()))
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+fn read_list(mut reader: impl BufRead) -> Result<HashMap<u64, usize>, ParseError> {
+    let mut stones_str = String::new();
+
+    reader.read_line(&mut stones_str)?;
+
+    let line = stones_str.trim_end();
+    let stones_strs: Vec<&str> = line.trim_start().split(" ").collect::<Vec<&str>>();
+
+    let stones: Vec<u64> =
+        collect_all(stones_strs.iter().map(|x| parse_token(1, line, x, "a number")))?;
+
+    let mut result = HashMap::new();
+    for stone in stones {
+        match result.get(&stone) {
+            Some(count) => {
+                result.insert(stone, count + 1);
+            }
+            None => {
+                result.insert(stone, 1);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn split_stone(stone: u64) -> Vec<u64> {
+    let s: String = stone.to_string();
+    let (s1, s2) = s.split_at(s.len() / 2);
+
+    vec![s1.parse().unwrap(), s2.parse().unwrap()]
+}
+
+fn even_digits(stone: u64) -> bool {
+    let s: String = stone.to_string();
+    s.len().is_multiple_of(2)
+}
+
+fn change_stone(stone: u64) -> Vec<u64> {
+    if stone == 0 {
+        // Rule 1: replace 0 with 1
+        vec![1]
+    } else if even_digits(stone) {
+        // Rule 2: even digits split into two stones
+        split_stone(stone)
+    } else {
+        // Rule 3: Multiply by 2024
+        vec![stone * 2024]
+    }
+}
+
+fn change_stones(stones: HashMap<u64, usize>) -> HashMap<u64, usize> {
+    let mut result = HashMap::new();
+
+    for (stone, count) in stones.into_iter() {
+        let new_stones = change_stone(stone);
+        for new_stone in new_stones {
+            match result.get(&new_stone) {
+                None => {
+                    result.insert(new_stone, count);
+                }
+                Some(i) => {
+                    result.insert(new_stone, i + count);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<u64, usize>;
+    const DAY: u8 = 11;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_list(reader)
+    }
+
+    fn part2(mut stones: Self::Input) -> Result<Option<String>, SolveError> {
+        for _ in 0..75 {
+            stones = change_stones(stones);
+        }
+
+        let total = stones.into_values().sum::<usize>();
+
+        Ok(Some(total.to_string()))
+    }
+}
@@ -0,0 +1,84 @@
+use aoc_common::{Dir4, ParseError, Point, Solution, SolveError};
+use std::io::BufRead;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type Garden = HashMap<Point, char>;
+
+fn read_garden(reader: impl BufRead) -> Result<Garden, ParseError> {
+    let mut garden: Garden = HashMap::new();
+
+    for (row, line) in reader.lines().enumerate() {
+        let line = line?;
+        for (col, plant) in line.chars().enumerate() {
+            garden.insert(Point::from((row, col)), plant);
+        }
+    }
+
+    Ok(garden)
+}
+
+fn region_neighbors(garden: &Garden, idx: Point, plant: char) -> Vec<Point> {
+    let mut neighbors = vec![];
+
+    for dir in Dir4::ALL {
+        let ni = idx.offset(dir);
+        if let Some(&v) = garden.get(&ni) {
+            if v == plant {
+                neighbors.push(ni);
+            }
+        }
+    }
+
+    neighbors
+}
+
+fn region_cost(garden: &mut Garden) -> u64 {
+    let mut area = 0;
+    let mut perimeter = 0;
+
+    let mut to_visit = HashSet::new();
+    let mut visited = HashSet::new();
+    to_visit.insert(*(garden.keys().next().unwrap()));
+
+    while !to_visit.is_empty() {
+        let current_idx = *(to_visit.iter().next().unwrap());
+        let current_plant = *(garden.get(&current_idx).unwrap());
+
+        to_visit.remove(&current_idx);
+        visited.insert(current_idx);
+
+        let neighbors = region_neighbors(garden, current_idx, current_plant);
+
+        area += 1;
+        perimeter += 4 - neighbors.len() as u64;
+        to_visit.extend(neighbors.into_iter().filter(|x| !visited.contains(x)));
+    }
+
+    // remove visited from garden
+    for i in &visited {
+        garden.remove(i);
+    }
+
+    area * perimeter
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+    const DAY: u8 = 12;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_garden(reader)
+    }
+
+    fn part1(mut garden: Self::Input) -> Result<Option<String>, SolveError> {
+        let mut total_cost = 0;
+        while !garden.is_empty() {
+            total_cost += region_cost(&mut garden);
+        }
+
+        Ok(Some(total_cost.to_string()))
+    }
+}
@@ -0,0 +1,163 @@
+use aoc_common::{Dir4, ParseError, Point, Solution, SolveError};
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+type Garden = HashMap<Point, char>;
+
+fn read_garden(reader: impl BufRead) -> Result<Garden, ParseError> {
+    let mut garden: Garden = HashMap::new();
+
+    for (row, line) in reader.lines().enumerate() {
+        let line = line?;
+        for (col, plant) in line.chars().enumerate() {
+            garden.insert(Point::from((row, col)), plant);
+        }
+    }
+
+    Ok(garden)
+}
+
+// Also return *missing* neighbors
+fn region_neighbors(garden: &Garden, idx: Point, plant: char) -> (Vec<Point>, Vec<Dir4>) {
+    let mut neighbors = vec![];
+    let mut missing_neighbors = vec![];
+
+    for dir in Dir4::ALL {
+        let ni = idx.offset(dir);
+        if let Some(&v) = garden.get(&ni) {
+            if v == plant {
+                neighbors.push(ni);
+            } else {
+                missing_neighbors.push(dir);
+            }
+        } else {
+            missing_neighbors.push(dir);
+        }
+    }
+
+    (neighbors, missing_neighbors)
+}
+
+fn condense_sides(nums: &[isize]) -> usize {
+    let mut numbers = nums.to_vec();
+    numbers.sort_unstable(); // Sort the numbers first
+
+    numbers
+        .iter()
+        .fold(Vec::new(), |mut acc, &x| {
+            match acc.last_mut() {
+                Some((_start, end)) if *end + 1 == x => *end = x,
+                _ => acc.push((x, x)),
+            };
+            acc
+        })
+        .len()
+}
+
+fn count_sides(sides: Vec<(Point, Dir4)>) -> usize {
+    // sides is a vector of fence segments
+    let mut nsides = 0;
+
+    let rowmax = sides
+        .iter()
+        .max_by_key(|&(p, _)| p.row)
+        .map(|(p, _)| p.row)
+        .unwrap();
+    for row in 0..rowmax+1 {
+        let thisrow_north: Vec<isize> = sides
+            .iter()
+            .filter(|(p, direction)| *direction == Dir4::Up && p.row == row)
+            .map(|(p, _)| p.col)
+            .collect();
+        let thisrow_south: Vec<isize> = sides
+            .iter()
+            .filter(|(p, direction)| *direction == Dir4::Down && p.row == row)
+            .map(|(p, _)| p.col)
+            .collect();
+        let nsides_north = condense_sides(&thisrow_north);
+        nsides += nsides_north;
+
+        let nsides_south = condense_sides(&thisrow_south);
+        nsides += nsides_south;
+    }
+
+    let colmax = sides
+        .iter()
+        .max_by_key(|&(p, _)| p.col)
+        .map(|(p, _)| p.col)
+        .unwrap();
+    for col in 0..colmax+1 {
+        let thiscol_east: Vec<isize> = sides
+            .iter()
+            .filter(|(p, direction)| *direction == Dir4::Right && p.col == col)
+            .map(|(p, _)| p.row)
+            .collect();
+        let thiscol_west: Vec<isize> = sides
+            .iter()
+            .filter(|(p, direction)| *direction == Dir4::Left && p.col == col)
+            .map(|(p, _)| p.row)
+            .collect();
+        let nsides_east = condense_sides(&thiscol_east);
+        nsides += nsides_east;
+
+        let nsides_west = condense_sides(&thiscol_west);
+        nsides += nsides_west;
+    }
+
+    nsides
+}
+
+fn region_cost(garden: &mut Garden) -> u64 {
+    let mut area = 0;
+    let mut sides = vec![];
+
+    let mut to_visit = HashSet::new();
+    let mut visited = HashSet::new();
+    to_visit.insert(*(garden.keys().next().unwrap()));
+
+    while !to_visit.is_empty() {
+        let current_idx = *(to_visit.iter().next().unwrap());
+        let current_plant = *(garden.get(&current_idx).unwrap());
+
+        to_visit.remove(&current_idx);
+        visited.insert(current_idx);
+
+        let (neighbors, missing_neighbors) = region_neighbors(garden, current_idx, current_plant);
+
+        area += 1;
+        for mn in missing_neighbors {
+            sides.push((current_idx, mn));
+        }
+        to_visit.extend(neighbors.into_iter().filter(|x| !visited.contains(x)));
+    }
+
+    // remove visited from garden
+    for i in &visited {
+        garden.remove(i);
+    }
+
+    let nsides = count_sides(sides) as u64;
+
+    area * nsides
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+    const DAY: u8 = 12;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_garden(reader)
+    }
+
+    fn part2(mut garden: Self::Input) -> Result<Option<String>, SolveError> {
+        let mut total_cost = 0;
+        while !garden.is_empty() {
+            total_cost += region_cost(&mut garden);
+        }
+
+        Ok(Some(total_cost.to_string()))
+    }
+}
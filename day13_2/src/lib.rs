@@ -0,0 +1,139 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use std::io::BufRead;
+use regex::Regex;
+use float_cmp::approx_eq;
+
+#[derive(Debug)]
+pub struct Machine {
+    ax: f64,
+    ay: f64,
+    bx: f64,
+    by: f64,
+    prizex: f64,
+    prizey: f64,
+}
+
+// Parse the two numbers re captures from line idx, which may be missing
+fn read_pair(
+    idx: usize,
+    line: Option<&String>,
+    re: &Regex,
+    expected: &str,
+) -> Result<(f64, f64), ParseError> {
+    let line = line.map_or("", |line| line.as_str());
+    let Some(caps) = re.captures(line) else {
+        return Err(ParseError::at_column(idx + 1, line, 1, expected));
+    };
+
+    Ok((
+        parse_token(idx + 1, line, caps.get(1).map_or("", |m| m.as_str()), "a number")?,
+        parse_token(idx + 1, line, caps.get(2).map_or("", |m| m.as_str()), "a number")?,
+    ))
+}
+
+// A machine is three lines: button A, button B and the prize
+fn parse_machine(lines: &[String], start: usize, res: &[Regex; 3]) -> Result<Machine, ParseError> {
+    if let Some(extra) = lines.get(3) {
+        let expected = "a blank line between machines";
+        return Err(ParseError::at_column(start + 4, extra, 1, expected));
+    }
+
+    let a = read_pair(start, lines.first(), &res[0], "Button A: X+<n>, Y+<n>");
+    let b = read_pair(start + 1, lines.get(1), &res[1], "Button B: X+<n>, Y+<n>");
+    let prize = read_pair(start + 2, lines.get(2), &res[2], "Prize: X=<n>, Y=<n>");
+
+    match (a, b, prize) {
+        (Ok((ax, ay)), Ok((bx, by)), Ok((prizex, prizey))) => Ok(Machine {
+            ax,
+            ay,
+            bx,
+            by,
+            prizex: prizex + 10000000000000.0,
+            prizey: prizey + 10000000000000.0,
+        }),
+        (a, b, prize) => {
+            let errors = a.err().into_iter().chain(b.err()).chain(prize.err());
+            Err(ParseError::from_all(errors.collect()).unwrap())
+        }
+    }
+}
+
+fn read_machines(reader: impl BufRead) -> Result<Vec<Machine>, ParseError> {
+    let res = [
+        Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap(),
+        Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap(),
+        Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap(),
+    ];
+
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let mut idx = 0;
+    let mut result = vec![];
+
+    loop {
+        // Machines are separated by blank lines, and there may be some at the end
+        while idx < lines.len() && lines[idx].is_empty() {
+            idx += 1;
+        }
+        if idx == lines.len() {
+            break;
+        }
+
+        let end = lines[idx..]
+            .iter()
+            .position(|line| line.is_empty())
+            .map_or(lines.len(), |len| idx + len);
+        result.push(parse_machine(&lines[idx..end], idx, &res));
+        idx = end;
+    }
+
+    if result.is_empty() {
+        return Err(ParseError::at_column(1, "", 1, "Button A: X+<n>, Y+<n>"));
+    }
+    collect_all(result)
+}
+
+// Find minimum # of tokens to win
+fn min_tokens(m: Machine) -> Option<f64> {
+    let mult = m.ax / m.bx;
+    if approx_eq!(f64, m.by * mult, m.ay) {
+        todo!("Colinear! {:#?}", m);
+    }
+
+    // prizex = a*ax + b*bx
+    // prizey = a*ay + b*by
+    let b = (m.prizex * m.ay / m.ax - m.prizey) / (m.bx * m.ay / m.ax - m.by);
+    let a = m.prizex / m.ax - b * m.bx / m.ax;
+
+    if a < 0.0 || b < 0.0 {
+        return None
+    }
+    if ! approx_eq!(f64, a.round(), a, ulps = 100) {
+        return None
+    }
+    if ! approx_eq!(f64, b.round(), b, ulps = 100) {
+        return None
+    }
+
+    Some(3.0*a + b)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    const DAY: u8 = 13;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_machines(reader)
+    }
+
+    fn part2(machines: Self::Input) -> Result<Option<String>, SolveError> {
+        let tokens = machines
+            .into_iter()
+            .map(min_tokens)
+            .map(|c| c.unwrap_or(0.0))
+            .sum::<f64>();
+
+        Ok(Some(tokens.to_string()))
+    }
+}
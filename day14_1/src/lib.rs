@@ -0,0 +1,90 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use regex::Regex;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Robot {
+    px: i32,
+    py: i32,
+    vx: i32,
+    vy: i32,
+}
+
+// Size of the space in the real puzzle; the examples use 11x7
+pub const WIDTH: i32 = 101;
+pub const HEIGHT: i32 = 103;
+
+fn parse_robot(re: &Regex, idx: usize, line: &str) -> Result<Robot, ParseError> {
+    let Some(caps) = re.captures(line) else {
+        return Err(ParseError::at_column(idx + 1, line, 1, "p=<x>,<y> v=<dx>,<dy>"));
+    };
+    let field = |i| caps.get(i).map_or("", |m| m.as_str());
+
+    Ok(Robot {
+        px: parse_token(idx + 1, line, field(1), "a position")?,
+        py: parse_token(idx + 1, line, field(2), "a position")?,
+        vx: parse_token(idx + 1, line, field(3), "a velocity")?,
+        vy: parse_token(idx + 1, line, field(4), "a velocity")?,
+    })
+}
+
+fn read_robots(reader: impl BufRead) -> Result<Vec<Robot>, ParseError> {
+    let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+
+    collect_all(lines.iter().enumerate().map(|(idx, line)| parse_robot(&re, idx, line)))
+}
+
+fn simulate_robot(robot: &Robot, width: i32, height: i32, steps: i32) -> (i32, i32) {
+    let result_x = (robot.px + steps * robot.vx + steps * width) % width;
+    let result_y = (robot.py + steps * robot.vy + steps * height) % height;
+
+    (result_x, result_y)
+}
+
+fn safety_factor(positions: Vec<(i32, i32)>, width: i32, height: i32) -> usize {
+    // Group by quadrant, discarding middle robots
+    // Count robots in each quadrant and multiply quadrant counts
+
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    let quadrant_1: Vec<_> = positions
+        .iter()
+        .filter(|p| p.0 < mid_x && p.1 < mid_y)
+        .collect();
+    let quadrant_2: Vec<_> = positions
+        .iter()
+        .filter(|p| p.0 < mid_x && p.1 > mid_y)
+        .collect();
+    let quadrant_3: Vec<_> = positions
+        .iter()
+        .filter(|p| p.0 > mid_x && p.1 < mid_y)
+        .collect();
+    let quadrant_4: Vec<_> = positions
+        .iter()
+        .filter(|p| p.0 > mid_x && p.1 > mid_y)
+        .collect();
+
+    quadrant_1.len() * quadrant_2.len() * quadrant_3.len() * quadrant_4.len()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<Robot>, i32, i32);
+    const DAY: u8 = 14;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok((read_robots(reader)?, WIDTH, HEIGHT))
+    }
+
+    fn part1((robots, width, height): Self::Input) -> Result<Option<String>, SolveError> {
+        let results: Vec<_> = robots
+            .iter()
+            .map(|r| simulate_robot(r, width, height, 100))
+            .collect();
+
+        Ok(Some(safety_factor(results, width, height).to_string()))
+    }
+}
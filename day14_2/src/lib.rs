@@ -0,0 +1,85 @@
+use aoc_common::{collect_all, parse_token, ParseError, Solution, SolveError};
+use regex::Regex;
+use std::io::BufRead;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Robot {
+    px: i32,
+    py: i32,
+    vx: i32,
+    vy: i32,
+}
+
+// Size of the space in the real puzzle; the examples use 11x7
+pub const WIDTH: i32 = 101;
+pub const HEIGHT: i32 = 103;
+
+fn parse_robot(re: &Regex, idx: usize, line: &str) -> Result<Robot, ParseError> {
+    let Some(caps) = re.captures(line) else {
+        return Err(ParseError::at_column(idx + 1, line, 1, "p=<x>,<y> v=<dx>,<dy>"));
+    };
+    let field = |i| caps.get(i).map_or("", |m| m.as_str());
+
+    Ok(Robot {
+        px: parse_token(idx + 1, line, field(1), "a position")?,
+        py: parse_token(idx + 1, line, field(2), "a position")?,
+        vx: parse_token(idx + 1, line, field(3), "a velocity")?,
+        vy: parse_token(idx + 1, line, field(4), "a velocity")?,
+    })
+}
+
+fn read_robots(reader: impl BufRead) -> Result<Vec<Robot>, ParseError> {
+    let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+
+    collect_all(lines.iter().enumerate().map(|(idx, line)| parse_robot(&re, idx, line)))
+}
+
+fn step_robot(robot: Robot, width: i32, height: i32) -> Robot {
+    let mut r = robot.clone();
+    r.px = (robot.px + robot.vx + width) % width;
+    r.py = (robot.py + robot.vy + height) % height;
+
+    r
+}
+
+fn render_grid(robots: &[Robot], width: i32, height: i32) -> String {
+    let mut result = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let n_robots = robots.iter().filter(|r| r.px == x && r.py == y).count();
+            if n_robots == 0 {
+                result.push('.');
+            } else {
+                result += &n_robots.to_string();
+            }
+        }
+        result.push('\n');
+    }
+
+    result
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<Robot>, i32, i32);
+    const DAY: u8 = 14;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        Ok((read_robots(reader)?, WIDTH, HEIGHT))
+    }
+
+    fn part2((mut robots, width, height): Self::Input) -> Result<Option<String>, SolveError> {
+        // I just printed them all out (up to the cycle length) and searched for a long string of '1's,
+        // which was a guess about what the tree might look like.
+        for _ in 1..6772 {
+            robots = robots
+                .into_iter()
+                .map(|r| step_robot(r, width, height))
+                .collect();
+        }
+
+        Ok(Some(render_grid(&robots, width, height)))
+    }
+}
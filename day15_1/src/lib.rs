@@ -0,0 +1,114 @@
+use aoc_common::{collect_all, parse_grid, Dir4, Grid, ParseError, Point, Solution, SolveError};
+use std::io::BufRead;
+
+fn read_moves(idx: usize, line: &str) -> Result<Vec<Dir4>, ParseError> {
+    collect_all(line.chars().enumerate().map(|(col, c)| {
+        Dir4::from_char(c).ok_or_else(|| {
+            let expected = "one of '^', 'v', '<' or '>'";
+            ParseError::at_column(idx + 1, line, col + 1, expected)
+        })
+    }))
+}
+
+fn read_row(idx: usize, line: &str) -> Result<Vec<char>, ParseError> {
+    collect_all(line.chars().enumerate().map(|(col, c)| match c {
+        '#' | 'O' | '.' | '@' => Ok(c),
+        _ => {
+            let expected = "one of '#', 'O', '.' or '@'";
+            Err(ParseError::at_column(idx + 1, line, col + 1, expected))
+        }
+    }))
+}
+
+fn read_warehouse(lines: &[String]) -> Result<Grid<char>, ParseError> {
+    collect_all::<_, Vec<_>>(lines.iter().enumerate().map(|(idx, line)| read_row(idx, line)))?;
+
+    let warehouse: Grid<char> = parse_grid(lines)?;
+    warehouse.expect_one('@', "the robot")?;
+
+    Ok(warehouse)
+}
+
+// The warehouse map, a blank line, then the robot's moves over any number of lines
+fn read_input(reader: impl BufRead) -> Result<(Grid<char>, Vec<Dir4>), ParseError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let blank = lines.iter().position(|line| line.is_empty()).unwrap_or(lines.len());
+
+    let warehouse = read_warehouse(&lines[..blank]);
+    let moves: Result<Vec<Vec<Dir4>>, _> = collect_all(
+        lines
+            .iter()
+            .enumerate()
+            .skip(blank + 1)
+            .map(|(idx, line)| read_moves(idx, line)),
+    );
+
+    match (warehouse, moves) {
+        (Ok(warehouse), Ok(moves)) => Ok((warehouse, moves.concat())),
+        (warehouse, moves) => {
+            let errors = warehouse.err().into_iter().chain(moves.err());
+            Err(ParseError::from_all(errors.collect()).unwrap())
+        }
+    }
+}
+
+fn process_move(warehouse: &Grid<char>, m: Dir4, pos: Point) -> Grid<char> {
+    let mut result = warehouse.clone();
+
+    let dest = pos.offset(m);
+
+    let me = result[pos];
+
+    // Check for wall
+    if result[dest] == '#' {
+        return result;
+    }
+
+    // Check for boulder
+    if result[dest] == 'O' {
+        result = process_move(warehouse, m, dest); // Try to move the boulder
+    }
+
+    // Move if there's a free space
+    if result[dest] == '.' {
+        result[dest] = me;
+        result[pos] = '.';
+    }
+
+    result
+}
+
+fn calculate_gps(warehouse: &Grid<char>) -> usize {
+    let mut result = 0;
+
+    for row in 0..warehouse.rows() {
+        for col in 0..warehouse.cols() {
+            if warehouse[(row, col)] == 'O' {
+                result += row * 100 + col;
+            }
+        }
+    }
+
+    result
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Grid<char>, Vec<Dir4>);
+    const DAY: u8 = 15;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1((warehouse, moves): Self::Input) -> Result<Option<String>, SolveError> {
+        let mut nw = warehouse.clone();
+        for m in moves {
+            let robot_pos = Point::from(nw.find(&'@').unwrap());
+            nw = process_move(&nw, m, robot_pos);
+        }
+
+        Ok(Some(calculate_gps(&nw).to_string()))
+    }
+}
@@ -0,0 +1,228 @@
+use aoc_common::{collect_all, parse_grid, Dir4, Grid, ParseError, Point, Solution, SolveError};
+use std::io::BufRead;
+
+fn expand_line(idx: usize, line: &str) -> Result<Vec<char>, ParseError> {
+    let expanded: Vec<[char; 2]> = collect_all(line.chars().enumerate().map(|(col, c)| match c {
+        '#' => Ok(['#', '#']),
+        'O' => Ok(['[', ']']),
+        '.' => Ok(['.', '.']),
+        '@' => Ok(['@', '.']),
+        _ => {
+            let expected = "one of '#', 'O', '.' or '@'";
+            Err(ParseError::at_column(idx + 1, line, col + 1, expected))
+        }
+    }))?;
+
+    Ok(expanded.concat())
+}
+
+fn read_moves(idx: usize, line: &str) -> Result<Vec<Dir4>, ParseError> {
+    collect_all(line.chars().enumerate().map(|(col, c)| {
+        Dir4::from_char(c).ok_or_else(|| {
+            let expected = "one of '^', 'v', '<' or '>'";
+            ParseError::at_column(idx + 1, line, col + 1, expected)
+        })
+    }))
+}
+
+// Check the map as given, then widen every tile
+fn read_warehouse(lines: &[String]) -> Result<Grid<char>, ParseError> {
+    let rows: Vec<Vec<char>> =
+        collect_all(lines.iter().enumerate().map(|(idx, line)| expand_line(idx, line)))?;
+
+    let original: Grid<char> = parse_grid(lines)?;
+    original.expect_one('@', "the robot")?;
+
+    Grid::from_rows(rows)
+}
+
+// The warehouse map, a blank line, then the robot's moves over any number of lines
+fn read_input(reader: impl BufRead) -> Result<(Grid<char>, Vec<Dir4>), ParseError> {
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let blank = lines.iter().position(|line| line.is_empty()).unwrap_or(lines.len());
+
+    let warehouse = read_warehouse(&lines[..blank]);
+    let moves: Result<Vec<Vec<Dir4>>, _> = collect_all(
+        lines
+            .iter()
+            .enumerate()
+            .skip(blank + 1)
+            .map(|(idx, line)| read_moves(idx, line)),
+    );
+
+    match (warehouse, moves) {
+        (Ok(warehouse), Ok(moves)) => Ok((warehouse, moves.concat())),
+        (warehouse, moves) => {
+            let errors = warehouse.err().into_iter().chain(moves.err());
+            Err(ParseError::from_all(errors.collect()).unwrap())
+        }
+    }
+}
+
+fn process_move(warehouse: &Grid<char>, m: Dir4, pos: Point) -> Grid<char> {
+    if warehouse[pos] == '@' {
+        move_robot(warehouse, m, pos)
+    } else if (warehouse[pos] == '[' || warehouse[pos] == ']') && !m.is_vertical() {
+        move_boulder_horiz(warehouse, m, pos)
+    } else if (warehouse[pos] == '[' || warehouse[pos] == ']') && m.is_vertical() {
+        move_boulder_vert(warehouse, m, pos)
+    } else {
+        unreachable!();
+    }
+}
+
+// if moving robot
+// if destination is wall, return
+// if destination is boulder, try to move boulder
+// if destination is clear, move
+fn move_robot(warehouse: &Grid<char>, m: Dir4, pos: Point) -> Grid<char> {
+    let mut result = warehouse.clone();
+
+    let dest = pos.offset(m);
+
+    let me = result[pos];
+
+    // Check for wall
+    if result[dest] == '#' {
+        return result;
+    }
+
+    // Check for boulder
+    if result[dest] == '[' || result[dest] == ']' {
+        result = process_move(warehouse, m, dest); // Try to move the boulder
+    }
+
+    // Move if there's a free space
+    if result[dest] == '.' {
+        result[dest] = me;
+        result[pos] = '.';
+    }
+
+    result
+}
+
+// if moving boulder horizontally look at two spots out
+// if destination is wall return
+// if destination is boulder, try to move boulder
+// if destination is clear, move
+fn move_boulder_horiz(warehouse: &Grid<char>, m: Dir4, pos: Point) -> Grid<char> {
+    let mut result = warehouse.clone();
+
+    assert!((result[pos] == '[' && m == Dir4::Right) || (result[pos] == ']' && m == Dir4::Left));
+
+    let dest = pos.offset_by(m, 2);
+
+    // Check for wall
+    if result[dest] == '#' {
+        return result;
+    }
+
+    // Check for boulder
+    if result[dest] == '[' || result[dest] == ']' {
+        result = process_move(warehouse, m, dest); // Try to move the boulder
+    }
+
+    // Move if there's a free space
+    if result[dest] == '.' {
+        let back = m.reverse();
+        result[dest] = result[pos.offset(m)];
+        result[dest.offset(back)] = result[pos];
+        result[dest.offset_by(back, 2)] = '.';
+    }
+
+    result
+}
+
+// if moving boulder vertically look at both destinations
+// if either is wall, return
+// if either is boulder, try to move boulder
+// if both are clear, move
+fn move_boulder_vert(warehouse: &Grid<char>, m: Dir4, pos: Point) -> Grid<char> {
+    let mut result = warehouse.clone();
+
+    assert!((result[pos] == '[' || result[pos] == ']') && m.is_vertical());
+
+    let source1 = pos;
+    let source2 = match result[pos] {
+        '[' => pos.offset(Dir4::Right),
+        ']' => pos.offset(Dir4::Left),
+        _ => unreachable!(),
+    };
+
+    let (dest1, dest2) = (source1.offset(m), source2.offset(m));
+
+    // Check for wall
+    if result[dest1] == '#' || result[dest2] == '#' {
+        return result;
+    }
+
+    if result[dest1] == '[' || result[dest1] == ']' {
+        result = process_move(&result, m, dest1);
+    }
+    if result[dest2] == '[' || result[dest2] == ']' {
+        result = process_move(&result, m, dest2);
+    }
+
+    if result[dest1] == '.' && result[dest2] == '.' {
+        result[dest1] = result[source1];
+        result[dest2] = result[source2];
+        result[source1] = '.';
+        result[source2] = '.';
+    } else {
+        return warehouse.clone();
+    }
+
+    result
+}
+
+fn calculate_gps(warehouse: &Grid<char>) -> usize {
+    let mut result = 0;
+
+    for row in 0..warehouse.rows() {
+        for col in 0..warehouse.cols() {
+            if warehouse[(row, col)] == '[' {
+                result += row * 100 + col;
+            }
+        }
+    }
+
+    result
+}
+
+#[allow(dead_code)]
+fn print_warehouse(warehouse: &Grid<char>) {
+    for row in 0..warehouse.rows() {
+        for col in 0..warehouse.cols() {
+            eprint!("{}", warehouse[(row, col)]);
+        }
+        eprintln!();
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Grid<char>, Vec<Dir4>);
+    const DAY: u8 = 15;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part2((warehouse, moves): Self::Input) -> Result<Option<String>, SolveError> {
+        let mut nw = warehouse.clone();
+        //    print_warehouse(&nw);
+        //    println!("");
+
+        for m in moves {
+            //        println!("{}", m);
+            let robot_pos = Point::from(nw.find(&'@').unwrap());
+            nw = process_move(&nw, m, robot_pos);
+
+            //        print_warehouse(&nw);
+            //        println!("");
+        }
+
+        Ok(Some(calculate_gps(&nw).to_string()))
+    }
+}
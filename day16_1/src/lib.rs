@@ -0,0 +1,18 @@
+use aoc_common::{Grid, ParseError, Solution, SolveError};
+use day16_maze::{read_maze, search_maze};
+use std::io::BufRead;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+    const DAY: u8 = 16;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_maze(reader)
+    }
+
+    fn part1(grid: Self::Input) -> Result<Option<String>, SolveError> {
+        Ok(Some(search_maze(&grid)?.cost.to_string()))
+    }
+}
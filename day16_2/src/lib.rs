@@ -0,0 +1,22 @@
+use aoc_common::{Grid, ParseError, Point, Solution, SolveError};
+use day16_maze::{read_maze, search_maze};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+    const DAY: u8 = 16;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_maze(reader)
+    }
+
+    fn part2(grid: Self::Input) -> Result<Option<String>, SolveError> {
+        let best = search_maze(&grid)?;
+        let best_tiles: HashSet<Point> = best.best_states.iter().map(|&(p, _)| p).collect();
+
+        Ok(Some(best_tiles.len().to_string()))
+    }
+}
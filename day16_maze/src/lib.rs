@@ -0,0 +1,49 @@
+// Day 16 reindeer maze, shared by both parts. The reindeer starts at S facing
+// east and scores 1 for each step forward and 1000 for each quarter turn.
+use aoc_common::{astar, read_grid, Dir4, Grid, ParseError, Point, ShortestPaths, SolveError};
+use std::io::BufRead;
+
+// The reindeer's state: where it is and which way it's facing
+pub type Reindeer = (Point, Dir4);
+
+// A maze of '#' walls with exactly one start S and one end E
+pub fn read_maze(reader: impl BufRead) -> Result<Grid<char>, ParseError> {
+    let grid: Grid<char> = read_grid(reader)?;
+    let start = grid.expect_one('S', "the start");
+    let end = grid.expect_one('E', "the end");
+
+    let errors = start.err().into_iter().chain(end.err()).collect();
+    match ParseError::from_all(errors) {
+        Some(err) => Err(err),
+        None => Ok(grid),
+    }
+}
+
+// Step forward for 1 point, or turn on the spot for 1000
+fn moves(maze: &Grid<char>, (position, direction): Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut result = vec![
+        ((position, direction.turn_left()), 1000),
+        ((position, direction.turn_right()), 1000),
+    ];
+
+    let ahead = position.offset(direction);
+    if maze.at(ahead).is_some_and(|&c| c != '#') {
+        result.push(((ahead, direction), 1));
+    }
+
+    result
+}
+
+// Find every lowest-scoring route from S, starting east, to E
+pub fn search_maze(maze: &Grid<char>) -> Result<ShortestPaths<Reindeer>, SolveError> {
+    let start = Point::from(maze.find(&'S').unwrap());
+    let end = Point::from(maze.find(&'E').unwrap());
+
+    astar(
+        (start, Dir4::Right),
+        |&reindeer| moves(maze, reindeer),
+        |&(position, _)| position.manhattan(end),
+        |&(position, _)| position == end,
+    )
+    .ok_or_else(|| SolveError::new("no path from S to E"))
+}
@@ -0,0 +1,29 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use day17_vm::{read_program, Computer};
+use std::io::BufRead;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (Computer, Vec<u64>);
+    const DAY: u8 = 17;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_program(reader)
+    }
+
+    fn part1((computer, program): Self::Input) -> Result<Option<String>, SolveError> {
+        let mut computer = computer.clone();
+
+        let results = computer
+            .run(&program)
+            .map_err(|err| SolveError::new(err.to_string()))?;
+        let result = results
+            .iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        Ok(Some(result))
+    }
+}
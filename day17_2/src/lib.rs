@@ -0,0 +1,20 @@
+use aoc_common::{ParseError, Solution, SolveError};
+use day17_vm::quine::solve_quine;
+use day17_vm::{read_program, Computer};
+use std::io::BufRead;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (Computer, Vec<u64>);
+    const DAY: u8 = 17;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        read_program(reader)
+    }
+
+    fn part2((computer, program): Self::Input) -> Result<Option<String>, SolveError> {
+        let a = solve_quine(&computer, &program).map_err(|err| SolveError::new(err.to_string()))?;
+        Ok(Some(a.to_string()))
+    }
+}
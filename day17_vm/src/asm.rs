@@ -0,0 +1,139 @@
+// Translate programs between the puzzle's comma-separated numbers and
+// readable mnemonics, one instruction per line:
+//
+//     bst a       ; combo operands 4-6 are written as the register they read
+//     bxl 5
+//     cdv b
+//     jnz 0
+//
+// Anything that doesn't decode as an instruction (an opcode or operand above
+// 7, or a trailing opcode with no operand) is written as `raw <n>` so every
+// program survives the round trip unchanged.
+use crate::isa::{InstructionSet, STOCK};
+use crate::Computer;
+use aoc_common::{collect_all, ParseError};
+
+fn combo_name(operand: u64) -> String {
+    match operand {
+        4 => "a".to_string(),
+        5 => "b".to_string(),
+        6 => "c".to_string(),
+        _ => operand.to_string(),
+    }
+}
+
+pub fn disasm(program: &[u64]) -> String {
+    disasm_with(&STOCK, program)
+}
+
+// Disassemble using the mnemonics of any instruction set
+pub fn disasm_with(isa: &InstructionSet, program: &[u64]) -> String {
+    let mut lines = vec![];
+
+    for chunk in program.chunks(2) {
+        let decoded = match *chunk {
+            [opcode, operand] if operand <= 7 => isa.get(opcode).map(|i| (i, operand)),
+            _ => None,
+        };
+
+        match decoded {
+            Some((instruction, operand)) => {
+                let operand = if instruction.takes_combo() {
+                    combo_name(operand)
+                } else {
+                    operand.to_string()
+                };
+                lines.push(format!("{} {}", instruction.mnemonic, operand));
+            }
+            None => lines.extend(chunk.iter().map(|n| format!("raw {}", n))),
+        }
+    }
+
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+fn parse_operand(idx: usize, line: &str, token: &str, combo: bool) -> Result<u64, ParseError> {
+    let value = match token {
+        "a" if combo => Some(4),
+        "b" if combo => Some(5),
+        "c" if combo => Some(6),
+        _ => token.parse().ok().filter(|&n| n <= 7),
+    };
+
+    value.ok_or_else(|| {
+        let expected = if combo {
+            "a, b, c or a number from 0 to 7"
+        } else {
+            "a number from 0 to 7"
+        };
+        ParseError::at_token(idx + 1, line, token, expected)
+    })
+}
+
+// Assemble one line into zero (blank or comment), one or two numbers
+fn asm_line(isa: &InstructionSet, idx: usize, line: &str) -> Result<Vec<u64>, ParseError> {
+    let code = line.split(';').next().unwrap_or_default();
+    let words: Vec<&str> = code.split_whitespace().collect();
+
+    match words.as_slice() {
+        [] => Ok(vec![]),
+        ["raw", n] => match n.parse() {
+            Ok(n) => Ok(vec![n]),
+            Err(_) => Err(ParseError::at_token(idx + 1, line, n, "a number")),
+        },
+        [mnemonic, operand] => {
+            let Some((opcode, instruction)) = isa.find(mnemonic) else {
+                return Err(ParseError::at_token(
+                    idx + 1,
+                    line,
+                    mnemonic,
+                    "an instruction",
+                ));
+            };
+            let operand = parse_operand(idx, line, operand, instruction.takes_combo())?;
+            Ok(vec![opcode, operand])
+        }
+        [mnemonic] => match isa.find(mnemonic) {
+            // bxc ignores its operand, so it can be left off
+            Some((opcode, _)) if *mnemonic == "bxc" => Ok(vec![opcode, 0]),
+            Some(_) => Err(ParseError::at_end(idx + 1, line, "an operand")),
+            None => Err(ParseError::at_token(
+                idx + 1,
+                line,
+                mnemonic,
+                "an instruction",
+            )),
+        },
+        [_, _, extra, ..] => Err(ParseError::at_token(idx + 1, line, extra, "end of line")),
+    }
+}
+
+// Assemble mnemonic source back into a program, reporting every bad line
+pub fn asm(source: &str) -> Result<Vec<u64>, ParseError> {
+    asm_with(&STOCK, source)
+}
+
+// Assemble using the mnemonics of any instruction set
+pub fn asm_with(isa: &InstructionSet, source: &str) -> Result<Vec<u64>, ParseError> {
+    let lines: Vec<Vec<u64>> = collect_all(
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| asm_line(isa, idx, line)),
+    )?;
+
+    Ok(lines.concat())
+}
+
+// Write a computer and program out in the puzzle input format read_program reads
+pub fn format_program(computer: &Computer, program: &[u64]) -> String {
+    let program: Vec<String> = program.iter().map(|n| n.to_string()).collect();
+
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        computer.reg_a,
+        computer.reg_b,
+        computer.reg_c,
+        program.join(",")
+    )
+}
@@ -0,0 +1,184 @@
+// Compile a program for the stock instruction set into pre-decoded ops, for
+// searches that run it millions of times. Opcodes are looked up and combo
+// operands resolved to a constant or a register once, up front, and the
+// registers live in locals rather than a Computer. Runs can stop after a
+// given number of outputs, so checking a candidate A against the first few
+// outputs doesn't pay for the rest of the program.
+use crate::{Computer, Opcode, VmError, DEFAULT_MAX_STEPS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Value(u64),
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Op {
+    Adv(Operand),
+    Bxl(u64),
+    Bst(Operand),
+    Jnz(usize),
+    Bxc,
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+    // Anything that can't execute fails with this when it's reached
+    Invalid(VmError),
+}
+
+#[derive(Debug, Clone)]
+pub struct Compiled {
+    // One op for every ip, since a jump can land on an odd one
+    ops: Vec<Op>,
+}
+
+fn compile_op(program: &[u64], ip: usize) -> Op {
+    let Some(&operand) = program.get(ip + 1) else {
+        return Op::Invalid(VmError::MissingOperand { ip });
+    };
+    let Some(opcode) = Opcode::decode(program[ip]) else {
+        return Op::Invalid(VmError::InvalidOpcode {
+            ip,
+            opcode: program[ip],
+        });
+    };
+
+    let combo = match operand {
+        0..=3 => Operand::Value(operand),
+        4 => Operand::A,
+        5 => Operand::B,
+        6 => Operand::C,
+        _ if opcode.takes_combo() => {
+            return Op::Invalid(VmError::InvalidComboOperand { ip, operand })
+        }
+        _ => Operand::Value(operand),
+    };
+
+    match opcode {
+        Opcode::ADV => Op::Adv(combo),
+        Opcode::BXL => Op::Bxl(operand),
+        Opcode::BST => Op::Bst(combo),
+        Opcode::JNZ => Op::Jnz(operand as usize),
+        Opcode::BXC => Op::Bxc,
+        Opcode::OUT => Op::Out(combo),
+        Opcode::BDV => Op::Bdv(combo),
+        Opcode::CDV => Op::Cdv(combo),
+    }
+}
+
+pub fn compile(program: &[u64]) -> Compiled {
+    Compiled {
+        ops: (0..program.len())
+            .map(|ip| compile_op(program, ip))
+            .collect(),
+    }
+}
+
+// value / 2^shift, which is 0 once the whole value has been shifted out
+fn divide(value: u64, shift: u64) -> u64 {
+    if shift >= u64::BITS as u64 {
+        0
+    } else {
+        value >> shift
+    }
+}
+
+impl Compiled {
+    // Run from the computer's state with register A replaced by a, handing
+    // each output to emit until the program halts or emit returns false.
+    // Gives up with an error after DEFAULT_MAX_STEPS instructions, as
+    // Computer::run does.
+    fn execute(
+        &self,
+        computer: &Computer,
+        a: u64,
+        mut emit: impl FnMut(u64) -> bool,
+    ) -> Result<(), VmError> {
+        let (mut a, mut b, mut c) = (a, computer.reg_b, computer.reg_c);
+        let mut ip = computer.ip;
+        let mut steps = 0;
+
+        while let Some(op) = self.ops.get(ip) {
+            if steps == DEFAULT_MAX_STEPS {
+                return Err(VmError::StepLimit { steps });
+            }
+            steps += 1;
+
+            let value = |operand| match operand {
+                Operand::Value(n) => n,
+                Operand::A => a,
+                Operand::B => b,
+                Operand::C => c,
+            };
+
+            match *op {
+                Op::Adv(operand) => a = divide(a, value(operand)),
+                Op::Bxl(n) => b ^= n,
+                Op::Bst(operand) => b = value(operand) % 8,
+                Op::Jnz(target) if a != 0 => {
+                    ip = target;
+                    continue;
+                }
+                Op::Jnz(_) => {}
+                Op::Bxc => b ^= c,
+                Op::Out(operand) => {
+                    if !emit(value(operand) % 8) {
+                        return Ok(());
+                    }
+                }
+                Op::Bdv(operand) => b = divide(a, value(operand)),
+                Op::Cdv(operand) => c = divide(a, value(operand)),
+                Op::Invalid(ref err) => return Err(err.clone()),
+            }
+            ip += 2;
+        }
+
+        Ok(())
+    }
+
+    // The first max_outputs outputs (or all of them, if fewer) from the
+    // computer's state
+    pub fn run(&self, computer: &Computer, max_outputs: usize) -> Result<Vec<u64>, VmError> {
+        let mut out = vec![];
+        if max_outputs > 0 {
+            self.execute(computer, computer.reg_a, |n| {
+                out.push(n);
+                out.len() < max_outputs
+            })?;
+        }
+
+        Ok(out)
+    }
+
+    // Whether starting with register A set to a outputs exactly target.
+    // Stops at the first output that differs, or one past the end.
+    pub fn outputs_exactly(&self, computer: &Computer, a: u64, target: &[u64]) -> bool {
+        let mut matched = 0;
+        let mut wrong = false;
+        let result = self.execute(computer, a, |n| {
+            if target.get(matched) == Some(&n) {
+                matched += 1;
+                true
+            } else {
+                wrong = true;
+                false
+            }
+        });
+
+        result.is_ok() && !wrong && matched == target.len()
+    }
+
+    // The first candidate value of A that outputs exactly target
+    pub fn find_a(
+        &self,
+        computer: &Computer,
+        candidates: impl IntoIterator<Item = u64>,
+        target: &[u64],
+    ) -> Option<u64> {
+        candidates
+            .into_iter()
+            .find(|&a| self.outputs_exactly(computer, a, target))
+    }
+}
@@ -0,0 +1,155 @@
+use crate::isa::InstructionSet;
+use crate::{Computer, DEFAULT_MAX_STEPS};
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "\
+step [n]       execute the next n instructions (default 1)
+continue       run until a breakpoint, the program halts or the step limit
+break <ip>     stop before executing the instruction at ip
+delete <ip>    remove a breakpoint
+print regs     show the registers and ip
+print out      show everything output so far
+reset          start again from the initial registers
+quit";
+
+// An interactive debugger over a single program. Every instruction executed
+// is traced along with its decoded combo operand.
+pub struct Debugger {
+    initial: Computer,
+    computer: Computer,
+    program: Vec<u64>,
+    isa: InstructionSet,
+    breakpoints: BTreeSet<usize>,
+    output: Vec<u64>,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer, program: Vec<u64>) -> Self {
+        Debugger {
+            initial: computer.clone(),
+            computer,
+            program,
+            isa: InstructionSet::stock(),
+            breakpoints: BTreeSet::new(),
+            output: vec![],
+        }
+    }
+
+    // Debug a program for an extended instruction set
+    pub fn with_instruction_set(mut self, isa: InstructionSet) -> Self {
+        self.isa = isa;
+        self
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn output(&self) -> &[u64] {
+        &self.output
+    }
+
+    // Execute one instruction, tracing it to out. Returns false once halted
+    // or stuck on an instruction that can't run.
+    fn step(&mut self, out: &mut impl Write) -> io::Result<bool> {
+        match self.isa.step(&mut self.computer, &self.program) {
+            Ok(Some(step)) => {
+                writeln!(out, "{}", step)?;
+                self.output.extend(step.output);
+                Ok(true)
+            }
+            Ok(None) => {
+                writeln!(out, "halted, output {}", self.output_string())?;
+                Ok(false)
+            }
+            Err(err) => {
+                writeln!(out, "error: {}", err)?;
+                Ok(false)
+            }
+        }
+    }
+
+    fn output_string(&self) -> String {
+        let output: Vec<String> = self.output.iter().map(|o| o.to_string()).collect();
+        output.join(",")
+    }
+
+    // Run a single command, writing anything it prints to out. Returns false
+    // when the command was quit.
+    pub fn command(&mut self, line: &str, out: &mut impl Write) -> io::Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            [] => {}
+            ["step" | "s"] => {
+                self.step(out)?;
+            }
+            ["step" | "s", n] => match n.parse::<usize>() {
+                Ok(n) => {
+                    for _ in 0..n {
+                        if !self.step(out)? {
+                            break;
+                        }
+                    }
+                }
+                Err(_) => writeln!(out, "step count must be a number")?,
+            },
+            ["continue" | "c"] => {
+                // Always move at least one instruction, so continuing from a
+                // breakpoint doesn't stop straight away
+                let mut steps = 0;
+                while self.step(out)? {
+                    steps += 1;
+                    if self.breakpoints.contains(&self.computer.ip) {
+                        writeln!(out, "breakpoint at {}", self.computer.ip)?;
+                        break;
+                    }
+                    if steps == DEFAULT_MAX_STEPS {
+                        writeln!(out, "still running after {} steps, stopping", steps)?;
+                        break;
+                    }
+                }
+            }
+            ["break" | "b", ip] => match ip.parse::<usize>() {
+                Ok(ip) => {
+                    self.breakpoints.insert(ip);
+                }
+                Err(_) => writeln!(out, "ip must be a number")?,
+            },
+            ["delete" | "d", ip] => match ip.parse::<usize>() {
+                Ok(ip) => {
+                    self.breakpoints.remove(&ip);
+                }
+                Err(_) => writeln!(out, "ip must be a number")?,
+            },
+            ["print" | "p", "regs"] => writeln!(out, "{}", self.computer)?,
+            ["print" | "p", "out"] => writeln!(out, "{}", self.output_string())?,
+            ["reset"] => {
+                self.computer = self.initial.clone();
+                self.output.clear();
+            }
+            ["help" | "h"] => writeln!(out, "{}", HELP)?,
+            ["quit" | "q"] => return Ok(false),
+            _ => writeln!(out, "unknown command '{}', try help", line.trim())?,
+        }
+
+        Ok(true)
+    }
+
+    // Read commands from input until quit or end of input
+    pub fn repl(&mut self, input: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        write!(out, "(vm) ")?;
+        out.flush()?;
+
+        for line in input.lines() {
+            if !self.command(&line?, &mut out)? {
+                return Ok(());
+            }
+            write!(out, "(vm) ")?;
+            out.flush()?;
+        }
+
+        writeln!(out)
+    }
+}
@@ -0,0 +1,282 @@
+// Instruction sets for the computer. An InstructionSet maps opcode numbers
+// to instructions, each with a mnemonic, the kind of operand it takes and a
+// function applying it to the computer. The core loop only ever looks
+// instructions up here, so variants of the puzzle's machine can add or
+// replace instructions without touching it:
+//
+//     let mut isa = InstructionSet::stock();
+//     isa.insert(8, Instruction::new("mul", OperandKind::Combo, |c, v| {
+//         c.reg_b = c.reg_b.wrapping_mul(v);
+//         Action::Next
+//     }));
+//
+// The stock table is the puzzle's eight instructions.
+use crate::{decode_combo_operand, Computer, Limits, Opcode, Step, VmError};
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    // Used as written
+    Literal,
+    // Decoded by the instruction set's combo decoder, by default a literal
+    // 0-3 or register A, B or C for 4-6
+    Combo,
+}
+
+// What happens after an instruction has been applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    // Carry on with the next instruction
+    Next,
+    Jump(usize),
+    // Output a value and carry on with the next instruction
+    Output(u64),
+    // Stop the program, as if ip had run off the end
+    Halt,
+    // The instruction can't be applied, for the reason given. Return this
+    // before changing anything, so the computer is left as it was.
+    Fault(&'static str),
+}
+
+// Apply an instruction to the computer given its (decoded) operand. The
+// instruction set moves ip afterwards according to the action returned.
+pub type Effect = fn(&mut Computer, u64) -> Action;
+
+// Decode a combo operand, or None if it isn't valid
+pub type ComboDecoder = fn(&Computer, u64) -> Option<u64>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub mnemonic: &'static str,
+    pub operand: OperandKind,
+    pub effect: Effect,
+}
+
+impl Instruction {
+    pub fn new(mnemonic: &'static str, operand: OperandKind, effect: Effect) -> Self {
+        Instruction {
+            mnemonic,
+            operand,
+            effect,
+        }
+    }
+
+    pub fn takes_combo(&self) -> bool {
+        self.operand == OperandKind::Combo
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InstructionSet {
+    instructions: BTreeMap<u64, Instruction>,
+    combo: ComboDecoder,
+}
+
+// value / 2^operand, which is 0 once the whole value has been shifted out
+fn divide(value: u64, operand: u64) -> u64 {
+    if operand >= u64::BITS as u64 {
+        0
+    } else {
+        value >> operand
+    }
+}
+
+fn stock_effect(opcode: Opcode) -> Effect {
+    match opcode {
+        Opcode::ADV => |c, v| {
+            c.reg_a = divide(c.reg_a, v);
+            Action::Next
+        },
+        Opcode::BXL => |c, v| {
+            c.reg_b ^= v;
+            Action::Next
+        },
+        Opcode::BST => |c, v| {
+            c.reg_b = v % 8;
+            Action::Next
+        },
+        Opcode::JNZ => |c, v| {
+            if c.reg_a == 0 {
+                Action::Next
+            } else {
+                Action::Jump(v as usize)
+            }
+        },
+        Opcode::BXC => |c, _| {
+            c.reg_b ^= c.reg_c;
+            Action::Next
+        },
+        Opcode::OUT => |_, v| Action::Output(v % 8),
+        Opcode::BDV => |c, v| {
+            c.reg_b = divide(c.reg_a, v);
+            Action::Next
+        },
+        Opcode::CDV => |c, v| {
+            c.reg_c = divide(c.reg_a, v);
+            Action::Next
+        },
+    }
+}
+
+// Shared by the computer's own step and run
+pub(crate) static STOCK: LazyLock<InstructionSet> = LazyLock::new(InstructionSet::stock);
+
+impl Default for InstructionSet {
+    fn default() -> Self {
+        Self::stock()
+    }
+}
+
+impl InstructionSet {
+    // No instructions at all, with the stock combo operands
+    pub fn empty() -> Self {
+        InstructionSet {
+            instructions: BTreeMap::new(),
+            combo: decode_combo_operand,
+        }
+    }
+
+    // The puzzle's eight instructions
+    pub fn stock() -> Self {
+        let mut isa = Self::empty();
+        for opcode in Opcode::ALL {
+            let operand = if opcode.takes_combo() {
+                OperandKind::Combo
+            } else {
+                OperandKind::Literal
+            };
+            isa.insert(
+                opcode as u64,
+                Instruction::new(opcode.mnemonic(), operand, stock_effect(opcode)),
+            );
+        }
+
+        isa
+    }
+
+    // Add an instruction, returning whatever had that opcode before
+    pub fn insert(&mut self, opcode: u64, instruction: Instruction) -> Option<Instruction> {
+        self.instructions.insert(opcode, instruction)
+    }
+
+    pub fn remove(&mut self, opcode: u64) -> Option<Instruction> {
+        self.instructions.remove(&opcode)
+    }
+
+    // Replace how combo operands are decoded, e.g. so 7 reads an extra register
+    pub fn set_combo_decoder(&mut self, combo: ComboDecoder) {
+        self.combo = combo;
+    }
+
+    pub fn get(&self, opcode: u64) -> Option<&Instruction> {
+        self.instructions.get(&opcode)
+    }
+
+    // The opcode and instruction with this mnemonic
+    pub fn find(&self, mnemonic: &str) -> Option<(u64, &Instruction)> {
+        self.instructions
+            .iter()
+            .find(|(_, instruction)| instruction.mnemonic == mnemonic)
+            .map(|(&opcode, instruction)| (opcode, instruction))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &Instruction)> + '_ {
+        self.instructions
+            .iter()
+            .map(|(&opcode, instruction)| (opcode, instruction))
+    }
+
+    // Execute the instruction at ip, or return None if the program has
+    // halted. On an error the computer is left unchanged.
+    pub fn step(&self, c: &mut Computer, program: &[u64]) -> Result<Option<Step>, VmError> {
+        if c.halted(program) {
+            return Ok(None);
+        }
+
+        // decode instruction and operand
+        let ip = c.ip;
+        let opcode = program[ip];
+        let Some(&operand) = program.get(ip + 1) else {
+            return Err(VmError::MissingOperand { ip });
+        };
+        let Some(instruction) = self.get(opcode) else {
+            return Err(VmError::InvalidOpcode { ip, opcode });
+        };
+
+        // decode combo operand if applicable
+        let value = match instruction.operand {
+            OperandKind::Literal => operand,
+            OperandKind::Combo => {
+                (self.combo)(c, operand).ok_or(VmError::InvalidComboOperand { ip, operand })?
+            }
+        };
+
+        // execute instruction and update Computer state
+        let mut output = None;
+        match (instruction.effect)(c, value) {
+            Action::Next => c.ip = ip + 2,
+            Action::Jump(target) => c.ip = target,
+            Action::Output(value) => {
+                output = Some(value);
+                c.ip = ip + 2;
+            }
+            Action::Halt => c.ip = program.len(),
+            Action::Fault(reason) => return Err(VmError::Fault { ip, reason }),
+        }
+
+        Ok(Some(Step {
+            ip,
+            opcode,
+            mnemonic: instruction.mnemonic,
+            combo: instruction.takes_combo(),
+            operand,
+            value,
+            output,
+        }))
+    }
+
+    // Run until the program halts, returning everything it output
+    pub fn run(
+        &self,
+        c: &mut Computer,
+        program: &[u64],
+        limits: Limits,
+    ) -> Result<Vec<u64>, VmError> {
+        let mut result = vec![];
+        let mut steps = 0;
+
+        // Brent's cycle detection: keep the state from the last power of two
+        // steps and watch for the machine coming back to it. Any cycle is
+        // caught once the power passes its length, without storing every state.
+        let mut saved = c.clone();
+        let mut power = 1;
+
+        loop {
+            let out_of_steps = limits.max_steps.is_some_and(|max| steps >= max);
+            if out_of_steps && !c.halted(program) {
+                return Err(VmError::StepLimit { steps });
+            }
+            let Some(step) = self.step(c, program)? else {
+                break;
+            };
+            result.extend(step.output);
+            steps += 1;
+
+            if limits.detect_loops {
+                if *c == saved {
+                    return Err(VmError::Loop {
+                        state: c.clone(),
+                        steps,
+                    });
+                }
+                if steps == power {
+                    saved = c.clone();
+                    power *= 2;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
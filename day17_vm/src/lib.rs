@@ -0,0 +1,332 @@
+// The day 17 3-bit computer, shared by both parts and the debugger
+use crate::isa::STOCK;
+use aoc_common::{collect_all, parse_token, ParseError};
+use regex::Regex;
+use std::fmt;
+use std::io::BufRead;
+
+pub mod asm;
+pub mod compile;
+pub mod debugger;
+pub mod isa;
+pub mod quine;
+pub mod sat;
+pub mod symbolic;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Computer {
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+    pub ip: usize,
+    // Registers beyond A, B and C and a stack, neither used by the stock
+    // instructions but there for extended instruction sets
+    pub extra: Vec<u64>,
+    pub stack: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Opcode {
+    ADV,
+    BXL,
+    BST,
+    JNZ,
+    BXC,
+    OUT,
+    BDV,
+    CDV,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 8] = [
+        Opcode::ADV,
+        Opcode::BXL,
+        Opcode::BST,
+        Opcode::JNZ,
+        Opcode::BXC,
+        Opcode::OUT,
+        Opcode::BDV,
+        Opcode::CDV,
+    ];
+
+    pub fn decode(item: u64) -> Option<Self> {
+        match item {
+            0 => Some(Self::ADV),
+            1 => Some(Self::BXL),
+            2 => Some(Self::BST),
+            3 => Some(Self::JNZ),
+            4 => Some(Self::BXC),
+            5 => Some(Self::OUT),
+            6 => Some(Self::BDV),
+            7 => Some(Self::CDV),
+            _ => None,
+        }
+    }
+
+    // Whether the operand is a combo operand (a literal 0-3 or a register)
+    // rather than a literal
+    pub fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::ADV | Opcode::BST | Opcode::OUT | Opcode::BDV | Opcode::CDV
+        )
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::ADV => "adv",
+            Opcode::BXL => "bxl",
+            Opcode::BST => "bst",
+            Opcode::JNZ => "jnz",
+            Opcode::BXC => "bxc",
+            Opcode::OUT => "out",
+            Opcode::BDV => "bdv",
+            Opcode::CDV => "cdv",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+}
+
+// The value a combo operand refers to, or None for the reserved 7 and up
+pub fn decode_combo_operand(computer: &Computer, operand: u64) -> Option<u64> {
+    match operand {
+        0..=3 => Some(operand),
+        4 => Some(computer.reg_a),
+        5 => Some(computer.reg_b),
+        6 => Some(computer.reg_c),
+        _ => None,
+    }
+}
+
+// Why a program couldn't run to completion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    // The program ends with an opcode and no operand
+    MissingOperand { ip: usize },
+    InvalidOpcode { ip: usize, opcode: u64 },
+    // Combo operand 7 is reserved
+    InvalidComboOperand { ip: usize, operand: u64 },
+    // An instruction from a custom instruction set couldn't be applied
+    Fault { ip: usize, reason: &'static str },
+    // Still running after the step budget ran out
+    StepLimit { steps: u64 },
+    // The machine came back to a state it had already been in, so it will
+    // never halt
+    Loop { state: Computer, steps: u64 },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::MissingOperand { ip } => write!(f, "no operand for the opcode at {}", ip),
+            VmError::InvalidOpcode { ip, opcode } => {
+                write!(f, "invalid opcode {} at {}", opcode, ip)
+            }
+            VmError::InvalidComboOperand { ip, operand } => {
+                write!(f, "invalid combo operand {} at {}", operand, ip)
+            }
+            VmError::Fault { ip, reason } => write!(f, "{} at {}", reason, ip),
+            VmError::StepLimit { steps } => write!(f, "still running after {} steps", steps),
+            VmError::Loop { state, steps } => {
+                write!(f, "infinite loop: {} repeats after {} steps", state, steps)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+// The most instructions run executes before giving up
+pub const DEFAULT_MAX_STEPS: u64 = 10_000_000;
+
+// Bounds on how long run_with lets a program go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub max_steps: Option<u64>,
+    // Stop as soon as a state (ip and registers) repeats
+    pub detect_loops: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_steps: Some(DEFAULT_MAX_STEPS),
+            detect_loops: true,
+        }
+    }
+}
+
+impl Limits {
+    pub fn unlimited() -> Self {
+        Limits {
+            max_steps: None,
+            detect_loops: false,
+        }
+    }
+}
+
+// Parse the comma-separated numbers re captures from lines[idx]
+fn read_field(
+    lines: &[String],
+    idx: usize,
+    re: &Regex,
+    expected: &str,
+) -> Result<Vec<u64>, ParseError> {
+    let Some(line) = lines.get(idx) else {
+        return Err(ParseError::at_column(idx + 1, "", 1, expected));
+    };
+    let Some(caps) = re.captures(line) else {
+        return Err(ParseError::at_column(idx + 1, line, 1, expected));
+    };
+
+    collect_all(
+        caps.get(1)
+            .map_or("", |m| m.as_str())
+            .split(",")
+            .map(|s| parse_token(idx + 1, line, s, "a number")),
+    )
+}
+
+// Three register lines, a blank line, then the program
+pub fn read_program(reader: impl BufRead) -> Result<(Computer, Vec<u64>), ParseError> {
+    let a_re = Regex::new(r"^Register A: (\d+)$").unwrap();
+    let b_re = Regex::new(r"^Register B: (\d+)$").unwrap();
+    let c_re = Regex::new(r"^Register C: (\d+)$").unwrap();
+    let program_re = Regex::new(r"^Program: ([\d,]+)$").unwrap();
+
+    let lines = reader.lines().collect::<Result<Vec<String>, _>>()?;
+    let mut errors = vec![];
+
+    let mut registers = vec![];
+    for (idx, re, expected) in [
+        (0, &a_re, "Register A: <n>"),
+        (1, &b_re, "Register B: <n>"),
+        (2, &c_re, "Register C: <n>"),
+    ] {
+        match read_field(&lines, idx, re, expected) {
+            Ok(value) => registers.push(value[0]),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if let Some(line) = lines.get(3).filter(|line| !line.is_empty()) {
+        errors.push(ParseError::at_column(
+            4,
+            line,
+            1,
+            "a blank line after the registers",
+        ));
+    }
+
+    let program = read_field(&lines, 4, &program_re, "Program: <n>,<n>,...");
+    let program = program.map_err(|err| errors.push(err)).unwrap_or_default();
+
+    for (idx, line) in lines.iter().enumerate().skip(5) {
+        if !line.is_empty() {
+            errors.push(ParseError::at_column(
+                idx + 1,
+                line,
+                1,
+                "end of input after the program",
+            ));
+        }
+    }
+
+    if let Some(err) = ParseError::from_all(errors) {
+        return Err(err);
+    }
+
+    Ok((
+        Computer::new(registers[0], registers[1], registers[2]),
+        program,
+    ))
+}
+
+// One executed instruction, as recorded in a trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub ip: usize,
+    pub opcode: u64,
+    pub mnemonic: &'static str,
+    // Whether the operand is a combo operand
+    pub combo: bool,
+    // The operand as written in the program
+    pub operand: u64,
+    // The operand the instruction actually used, after combo decoding
+    pub value: u64,
+    pub output: Option<u64>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>3}: {} {}", self.ip, self.mnemonic, self.operand)?;
+        if self.combo && self.operand >= 4 {
+            match ["a", "b", "c"].get(self.operand as usize - 4) {
+                Some(register) => write!(f, " ({} = {})", register, self.value)?,
+                None => write!(f, " (= {})", self.value)?,
+            }
+        }
+        if let Some(output) = self.output {
+            write!(f, " -> {}", output)?;
+        }
+        Ok(())
+    }
+}
+
+impl Computer {
+    pub fn new(reg_a: u64, reg_b: u64, reg_c: u64) -> Self {
+        Computer {
+            reg_a,
+            reg_b,
+            reg_c,
+            ip: 0,
+            extra: vec![],
+            stack: vec![],
+        }
+    }
+
+    // Halt when IP is past the end
+    pub fn halted(&self, program: &[u64]) -> bool {
+        self.ip + 1 > program.len()
+    }
+
+    // Execute the instruction at ip with the stock instruction set, or
+    // return None if the program has halted. On an error the computer is
+    // left unchanged.
+    pub fn step(&mut self, program: &[u64]) -> Result<Option<Step>, VmError> {
+        STOCK.step(self, program)
+    }
+
+    // Run until the program halts, returning everything it output, within
+    // the default limits
+    pub fn run(&mut self, program: &[u64]) -> Result<Vec<u64>, VmError> {
+        self.run_with(program, Limits::default())
+    }
+
+    pub fn run_with(&mut self, program: &[u64], limits: Limits) -> Result<Vec<u64>, VmError> {
+        STOCK.run(self, program, limits)
+    }
+}
+
+impl fmt::Display for Computer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "a = {}, b = {}, c = {}, ip = {}",
+            self.reg_a, self.reg_b, self.reg_c, self.ip
+        )?;
+        if !self.extra.is_empty() {
+            write!(f, ", extra = {:?}", self.extra)?;
+        }
+        if !self.stack.is_empty() {
+            write!(f, ", stack = {:?}", self.stack)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use aoc_common::{InputSource, ParseError};
+use day17_vm::asm::{asm, disasm, format_program};
+use day17_vm::debugger::Debugger;
+use day17_vm::quine::smallest_a_any;
+use day17_vm::symbolic;
+use day17_vm::{read_program, Computer, Limits};
+use std::env;
+use std::io;
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} [debug] <input>", program);
+    eprintln!("       {} run <input> [<max steps>]", program);
+    eprintln!("       {} disasm <input>", program);
+    eprintln!("       {} asm <source> [<a> <b> <c>]", program);
+    eprintln!("       {} quine [--sat] <input> [<target>]", program);
+    eprintln!();
+    eprintln!("<input> and <source> are a file path, - for stdin, or --input-str <text>");
+    eprintln!("<target> is comma-separated output to search for, by default the program itself");
+    eprintln!("--sat always uses symbolic execution, rather than only for unusual programs");
+    std::process::exit(1);
+}
+
+fn exit_on_error<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    })
+}
+
+fn load(source: &InputSource) -> (Computer, Vec<u64>) {
+    exit_on_error(
+        source
+            .reader()
+            .and_then(read_program)
+            .map_err(|err| err.with_file(source.path())),
+    )
+}
+
+fn debug(source: InputSource) {
+    // Commands come from stdin, so the program can't
+    if let InputSource::Stdin = source {
+        eprintln!("The program must come from a file or --input-str");
+        std::process::exit(1);
+    }
+
+    let (computer, program) = load(&source);
+    let mut debugger = Debugger::new(computer, program);
+    if let Err(err) = debugger.repl(io::stdin().lock(), io::stdout()) {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let name = &args[0];
+
+    let (command, mut rest) = match args.get(1).map(|arg| arg.as_str()) {
+        Some(command @ ("debug" | "run" | "disasm" | "asm" | "quine")) => (command, &args[2..]),
+        _ => ("debug", &args[1..]),
+    };
+    let sat = command == "quine" && rest.first().is_some_and(|arg| arg == "--sat");
+    if sat {
+        rest = &rest[1..];
+    }
+    let Some((source, rest)) = InputSource::from_args(rest) else {
+        usage(name);
+    };
+
+    match (command, rest) {
+        ("debug", []) => debug(source),
+        ("run", []) | ("run", [_]) => {
+            let (mut computer, program) = load(&source);
+            let mut limits = Limits::default();
+            if let [max_steps] = rest {
+                limits.max_steps = Some(max_steps.parse().unwrap_or_else(|_| usage(name)));
+            }
+
+            match computer.run_with(&program, limits) {
+                Ok(output) => {
+                    let output: Vec<String> = output.iter().map(|o| o.to_string()).collect();
+                    println!("{}", output.join(","));
+                }
+                Err(err) => {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        ("disasm", []) => {
+            let (_, program) = load(&source);
+            print!("{}", disasm(&program));
+        }
+        ("asm", []) | ("asm", [_, _, _]) => {
+            let registers: Vec<u64> = rest
+                .iter()
+                .map(|r| r.parse().unwrap_or_else(|_| usage(name)))
+                .collect();
+            let computer = match registers[..] {
+                [a, b, c] => Computer::new(a, b, c),
+                _ => Computer::new(0, 0, 0),
+            };
+
+            let text = exit_on_error(source.read_to_string());
+            let assembled = exit_on_error(asm(&text).map_err(|err| err.with_file(source.path())));
+            print!("{}", format_program(&computer, &assembled));
+        }
+        ("quine", []) | ("quine", [_]) => {
+            let (computer, program) = load(&source);
+            let target: Vec<u64> = match rest {
+                [target] => target
+                    .split(',')
+                    .map(|n| n.parse().unwrap_or_else(|_| usage(name)))
+                    .collect(),
+                _ => program.clone(),
+            };
+
+            let result = if sat {
+                symbolic::smallest_a(&computer, &program, &target)
+            } else {
+                smallest_a_any(&computer, &program, &target)
+            };
+            match result {
+                Ok(a) => println!("{}", a),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    std::process::exit(1);
+                }
+            }
+        }
+        _ => usage(name),
+    }
+}
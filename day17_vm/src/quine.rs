@@ -0,0 +1,147 @@
+// Find values of register A that make a program output a given sequence.
+//
+// This works for programs shaped like the puzzle's: a single loop ending in
+// `jnz 0`, with one `out` and one `adv` shifting A right by a constant k bits
+// per pass, and B and C recomputed from A each time round. Each output then
+// depends only on A at the start of its pass, and the last pass has A below
+// 2^k, so A can be built k bits at a time starting from the last output.
+use crate::compile::{compile, Compiled};
+use crate::symbolic;
+use crate::{Computer, Opcode};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuineError {
+    // The program isn't a loop this solver understands
+    Unsupported(String),
+    // The program is supported but nothing produces the target
+    NoSolution,
+}
+
+impl fmt::Display for QuineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuineError::Unsupported(reason) => write!(f, "unsupported program: {}", reason),
+            QuineError::NoSolution => write!(f, "no value of A produces the target output"),
+        }
+    }
+}
+
+impl std::error::Error for QuineError {}
+
+fn unsupported<T>(reason: &str) -> Result<T, QuineError> {
+    Err(QuineError::Unsupported(reason.to_string()))
+}
+
+// Check the program is a supported loop and return how many bits of A each
+// pass consumes
+pub fn analyse(program: &[u64]) -> Result<u32, QuineError> {
+    if !program.len().is_multiple_of(2) || program.iter().any(|&n| n > 7) {
+        return unsupported("not a sequence of valid instructions");
+    }
+    let instructions: Vec<(Opcode, u64)> = program
+        .chunks(2)
+        .filter_map(|pair| Some((Opcode::decode(pair[0])?, pair[1])))
+        .collect();
+
+    let Some((&last, body)) = instructions.split_last() else {
+        return unsupported("empty program");
+    };
+    if last != (Opcode::JNZ, 0) {
+        return unsupported("the program must end with jnz 0");
+    }
+
+    let mut shift = None;
+    let mut outputs = 0;
+    let (mut b_set, mut c_set) = (false, false);
+
+    for &(opcode, operand) in body {
+        // Registers read before they're written would carry state from one
+        // pass to the next
+        let reads_b =
+            matches!(opcode, Opcode::BXL | Opcode::BXC) || (opcode.takes_combo() && operand == 5);
+        let reads_c = opcode == Opcode::BXC || (opcode.takes_combo() && operand == 6);
+        if (reads_b && !b_set) || (reads_c && !c_set) {
+            return unsupported("B or C is carried over between passes of the loop");
+        }
+
+        match opcode {
+            Opcode::ADV if shift.is_some() => return unsupported("more than one adv"),
+            Opcode::ADV if (1..=3).contains(&operand) => shift = Some(operand as u32),
+            Opcode::ADV => return unsupported("adv must shift A by a constant 1-3 bits"),
+            Opcode::JNZ => return unsupported("jumps other than the final jnz 0"),
+            Opcode::OUT => outputs += 1,
+            Opcode::BST | Opcode::BDV | Opcode::BXL => b_set = true,
+            Opcode::CDV => c_set = true,
+            Opcode::BXC => {}
+        }
+    }
+
+    if outputs != 1 {
+        return unsupported("the loop must output exactly once per pass");
+    }
+    match shift {
+        Some(shift) => Ok(shift),
+        None => unsupported("no adv, so A never changes"),
+    }
+}
+
+// Choose the k bits of A for pass i, given the bits above it. Trying digits
+// in ascending order from the most significant end means the first complete
+// match is the smallest.
+fn search(
+    computer: &Computer,
+    program: &Compiled,
+    target: &[u64],
+    shift: u32,
+    i: usize,
+    high: u64,
+) -> Option<u64> {
+    for digit in 0..(1 << shift) {
+        let a = (high << shift) | digit;
+        if !program.outputs_exactly(computer, a, &target[i..]) {
+            continue;
+        }
+        if i == 0 {
+            return Some(a);
+        }
+        if let Some(a) = search(computer, program, target, shift, i - 1, a) {
+            return Some(a);
+        }
+    }
+
+    None
+}
+
+// The smallest value of A for which the program outputs exactly target
+pub fn smallest_a(computer: &Computer, program: &[u64], target: &[u64]) -> Result<u64, QuineError> {
+    let shift = analyse(program)?;
+
+    if target.is_empty() || target.iter().any(|&n| n > 7) {
+        return Err(QuineError::NoSolution);
+    }
+    if target.len() as u32 * shift > u64::BITS {
+        return unsupported("the target needs more than 64 bits of A");
+    }
+
+    let compiled = compile(program);
+    search(computer, &compiled, target, shift, target.len() - 1, 0).ok_or(QuineError::NoSolution)
+}
+
+// Like smallest_a, but handing programs it doesn't support to the much
+// slower symbolic execution, which copes with any control flow
+pub fn smallest_a_any(
+    computer: &Computer,
+    program: &[u64],
+    target: &[u64],
+) -> Result<u64, QuineError> {
+    match smallest_a(computer, program, target) {
+        Err(QuineError::Unsupported(_)) => symbolic::smallest_a(computer, program, target),
+        result => result,
+    }
+}
+
+// The smallest value of A for which the program outputs itself
+pub fn solve_quine(computer: &Computer, program: &[u64]) -> Result<u64, QuineError> {
+    smallest_a_any(computer, program, program)
+}
@@ -0,0 +1,228 @@
+// A small DPLL SAT solver: unit propagation over two watched literals,
+// chronological backtracking and no clause learning. It's meant for the
+// circuits symbolic execution builds, where deciding the input bits in a
+// sensible order lets propagation do most of the work.
+use std::ops::Not;
+
+// A variable or its negation, packed as var * 2 + negated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Lit(u32);
+
+impl Lit {
+    pub fn new(var: usize, positive: bool) -> Self {
+        Lit((var as u32) << 1 | !positive as u32)
+    }
+
+    pub fn var(self) -> usize {
+        (self.0 >> 1) as usize
+    }
+
+    pub fn is_positive(self) -> bool {
+        self.0 & 1 == 0
+    }
+
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Not for Lit {
+    type Output = Lit;
+
+    fn not(self) -> Lit {
+        Lit(self.0 ^ 1)
+    }
+}
+
+// A formula in conjunctive normal form: every clause must have at least one
+// true literal
+#[derive(Debug, Clone, Default)]
+pub struct Cnf {
+    num_vars: usize,
+    clauses: Vec<Vec<Lit>>,
+}
+
+impl Cnf {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_var(&mut self) -> Lit {
+        self.num_vars += 1;
+        Lit::new(self.num_vars - 1, true)
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    pub fn clauses(&self) -> &[Vec<Lit>] {
+        &self.clauses
+    }
+
+    pub fn add_clause(&mut self, clause: Vec<Lit>) {
+        self.clauses.push(clause);
+    }
+}
+
+struct Solver<'a> {
+    clauses: Vec<&'a [Lit]>,
+    // For each clause, the positions of its two watched literals
+    watched: Vec<[usize; 2]>,
+    // For each literal, the clauses watching it
+    watches: Vec<Vec<usize>>,
+    values: Vec<Option<bool>>,
+    trail: Vec<Lit>,
+    propagated: usize,
+    // Trail length at each decision, the decision, whether it's already been
+    // flipped, and where in the decision order it was made
+    decisions: Vec<(usize, Lit, bool, usize)>,
+}
+
+impl<'a> Solver<'a> {
+    fn value(&self, lit: Lit) -> Option<bool> {
+        self.values[lit.var()].map(|v| v == lit.is_positive())
+    }
+
+    fn assign(&mut self, lit: Lit) {
+        self.values[lit.var()] = Some(lit.is_positive());
+        self.trail.push(lit);
+    }
+
+    // Propagate everything on the trail, returning false on a conflict
+    fn propagate(&mut self) -> bool {
+        while self.propagated < self.trail.len() {
+            let falsified = !self.trail[self.propagated];
+            self.propagated += 1;
+
+            let watching = std::mem::take(&mut self.watches[falsified.index()]);
+            let mut keep = Vec::with_capacity(watching.len());
+            let mut conflict = false;
+
+            for (n, &clause_idx) in watching.iter().enumerate() {
+                if conflict {
+                    keep.extend_from_slice(&watching[n..]);
+                    break;
+                }
+
+                let clause = self.clauses[clause_idx];
+                let [w0, w1] = self.watched[clause_idx];
+                let (mine, other) = if clause[w0] == falsified {
+                    (0, w1)
+                } else {
+                    (1, w0)
+                };
+
+                if self.value(clause[other]) == Some(true) {
+                    keep.push(clause_idx);
+                    continue;
+                }
+
+                // Look for another literal to watch
+                let replacement = (0..clause.len())
+                    .find(|&i| i != w0 && i != w1 && self.value(clause[i]) != Some(false));
+                if let Some(i) = replacement {
+                    self.watched[clause_idx][mine] = i;
+                    self.watches[clause[i].index()].push(clause_idx);
+                    continue;
+                }
+
+                keep.push(clause_idx);
+                match self.value(clause[other]) {
+                    None => self.assign(clause[other]),
+                    Some(_) => conflict = true,
+                }
+            }
+
+            self.watches[falsified.index()] = keep;
+            if conflict {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // Undo the most recent unflipped decision and try the other value,
+    // returning where in the decision order to carry on from, or None once
+    // there's nothing left to flip
+    fn backtrack(&mut self) -> Option<usize> {
+        while let Some((len, lit, flipped, pos)) = self.decisions.pop() {
+            for undone in self.trail.drain(len..) {
+                self.values[undone.var()] = None;
+            }
+            self.propagated = len;
+
+            if !flipped {
+                self.decisions.push((len, !lit, true, pos));
+                self.assign(!lit);
+                return Some(pos);
+            }
+        }
+
+        None
+    }
+}
+
+// Find an assignment satisfying cnf with every assumption true, or None if
+// there isn't one. Variables are decided in `order` first (trying false
+// before true), then in numeric order.
+pub fn solve(cnf: &Cnf, order: &[usize], assumptions: &[Lit]) -> Option<Vec<bool>> {
+    let mut solver = Solver {
+        clauses: vec![],
+        watched: vec![],
+        watches: vec![vec![]; cnf.num_vars * 2],
+        values: vec![None; cnf.num_vars],
+        trail: vec![],
+        propagated: 0,
+        decisions: vec![],
+    };
+
+    let mut units = assumptions.to_vec();
+    for clause in &cnf.clauses {
+        match clause.len() {
+            0 => return None,
+            1 => units.push(clause[0]),
+            _ => {
+                let idx = solver.clauses.len();
+                solver.clauses.push(clause);
+                solver.watched.push([0, 1]);
+                solver.watches[clause[0].index()].push(idx);
+                solver.watches[clause[1].index()].push(idx);
+            }
+        }
+    }
+
+    for lit in units {
+        match solver.value(lit) {
+            Some(true) => {}
+            Some(false) => return None,
+            None => solver.assign(lit),
+        }
+    }
+
+    // Everything before pos in the order was assigned when the latest
+    // decision was made, so stays assigned until that decision is undone
+    let order: Vec<usize> = order.iter().copied().chain(0..cnf.num_vars).collect();
+    let mut pos = 0;
+    loop {
+        if !solver.propagate() {
+            match solver.backtrack() {
+                Some(decided_at) => pos = decided_at,
+                None => return None,
+            }
+            continue;
+        }
+
+        while pos < order.len() && solver.values[order[pos]].is_some() {
+            pos += 1;
+        }
+        if pos == order.len() {
+            return Some(solver.values.iter().map(|v| v.unwrap()).collect());
+        }
+
+        let lit = Lit::new(order[pos], false);
+        solver.decisions.push((solver.trail.len(), lit, false, pos));
+        solver.assign(lit);
+    }
+}
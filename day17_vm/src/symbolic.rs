@@ -0,0 +1,337 @@
+// Run a program with register A unknown. Every bit of A is a SAT variable,
+// instructions build circuits over those bits, and each output comes out as
+// three boolean formulas. Branches on jnz are explored both ways, so the
+// result is every path through the program along with the conditions on A
+// for taking it. The SAT solver can then find an A giving any output.
+use crate::quine::QuineError;
+use crate::sat::{self, Cnf, Lit};
+use crate::{Computer, Opcode};
+use std::ops::Not;
+
+// Registers are this many bits wide
+const WIDTH: usize = 64;
+
+// Give up on a path after this many instructions, e.g. a loop that never
+// changes A
+const MAX_STEPS: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bit {
+    Const(bool),
+    Var(Lit),
+}
+
+impl Not for Bit {
+    type Output = Bit;
+
+    fn not(self) -> Bit {
+        match self {
+            Bit::Const(b) => Bit::Const(!b),
+            Bit::Var(lit) => Bit::Var(!lit),
+        }
+    }
+}
+
+// A register's value, least significant bit first
+pub type Word = Vec<Bit>;
+
+fn constant(value: u64) -> Word {
+    (0..WIDTH)
+        .map(|i| Bit::Const(value >> i & 1 == 1))
+        .collect()
+}
+
+// Builds gates into a CNF formula, folding constants away as it goes
+#[derive(Debug, Default)]
+struct Circuit {
+    cnf: Cnf,
+}
+
+impl Circuit {
+    fn and(&mut self, x: Bit, y: Bit) -> Bit {
+        match (x, y) {
+            (Bit::Const(false), _) | (_, Bit::Const(false)) => Bit::Const(false),
+            (Bit::Const(true), other) | (other, Bit::Const(true)) => other,
+            (Bit::Var(a), Bit::Var(b)) if a == b => x,
+            (Bit::Var(a), Bit::Var(b)) if a == !b => Bit::Const(false),
+            (Bit::Var(a), Bit::Var(b)) => {
+                let z = self.cnf.new_var();
+                self.cnf.add_clause(vec![!z, a]);
+                self.cnf.add_clause(vec![!z, b]);
+                self.cnf.add_clause(vec![z, !a, !b]);
+                Bit::Var(z)
+            }
+        }
+    }
+
+    fn or(&mut self, x: Bit, y: Bit) -> Bit {
+        !self.and(!x, !y)
+    }
+
+    fn xor(&mut self, x: Bit, y: Bit) -> Bit {
+        match (x, y) {
+            (Bit::Const(c), other) | (other, Bit::Const(c)) => {
+                if c {
+                    !other
+                } else {
+                    other
+                }
+            }
+            (Bit::Var(a), Bit::Var(b)) if a == b => Bit::Const(false),
+            (Bit::Var(a), Bit::Var(b)) if a == !b => Bit::Const(true),
+            (Bit::Var(a), Bit::Var(b)) => {
+                let z = self.cnf.new_var();
+                self.cnf.add_clause(vec![!z, a, b]);
+                self.cnf.add_clause(vec![!z, !a, !b]);
+                self.cnf.add_clause(vec![z, !a, b]);
+                self.cnf.add_clause(vec![z, a, !b]);
+                Bit::Var(z)
+            }
+        }
+    }
+
+    // if_set when sel is true, otherwise if_clear
+    fn mux(&mut self, sel: Bit, if_set: Bit, if_clear: Bit) -> Bit {
+        if if_set == if_clear {
+            return if_set;
+        }
+        let set = self.and(sel, if_set);
+        let clear = self.and(!sel, if_clear);
+        self.or(set, clear)
+    }
+
+    fn any(&mut self, bits: &[Bit]) -> Bit {
+        bits.iter()
+            .fold(Bit::Const(false), |acc, &bit| self.or(acc, bit))
+    }
+
+    fn xor_words(&mut self, x: &Word, y: &Word) -> Word {
+        x.iter().zip(y).map(|(&a, &b)| self.xor(a, b)).collect()
+    }
+
+    // word >> amount, as a barrel shifter over amount's bits
+    fn shift_right(&mut self, word: &Word, amount: &Word) -> Word {
+        let stages = WIDTH.trailing_zeros() as usize;
+        let mut result = word.clone();
+
+        for (stage, &sel) in amount.iter().enumerate().take(stages) {
+            let by = 1 << stage;
+            result = (0..WIDTH)
+                .map(|i| {
+                    let shifted = result.get(i + by).copied().unwrap_or(Bit::Const(false));
+                    self.mux(sel, shifted, result[i])
+                })
+                .collect();
+        }
+
+        // Shifting by the width or more leaves nothing
+        let too_far = self.any(&amount[stages..]);
+        result.iter().map(|&bit| self.and(!too_far, bit)).collect()
+    }
+}
+
+fn low3(word: &Word) -> Word {
+    (0..WIDTH)
+        .map(|i| if i < 3 { word[i] } else { Bit::Const(false) })
+        .collect()
+}
+
+// One way through the program: the outputs it makes and the conditions on A
+// (each of which must be true) for it to be taken
+#[derive(Debug, Clone)]
+pub struct Path {
+    pub outputs: Vec<[Bit; 3]>,
+    pub conditions: Vec<Bit>,
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    a: Word,
+    b: Word,
+    c: Word,
+    ip: usize,
+    steps: usize,
+    path: Path,
+}
+
+// The result of running a program symbolically
+#[derive(Debug)]
+pub struct SymbolicRun {
+    circuit: Circuit,
+    // The variables for A's bits, least significant first
+    pub a: Vec<Lit>,
+    // Every path that halts, having made at most the requested number of outputs
+    pub paths: Vec<Path>,
+}
+
+fn combo(state: &State, operand: u64) -> Option<Word> {
+    match operand {
+        0..=3 => Some(constant(operand)),
+        4 => Some(state.a.clone()),
+        5 => Some(state.b.clone()),
+        6 => Some(state.c.clone()),
+        _ => None,
+    }
+}
+
+// Execute one instruction. Returns the states it leads to, two for a jnz
+// that could go either way, or none if the path is dead (an invalid
+// instruction, too many outputs, an impossible condition).
+fn step(circuit: &mut Circuit, mut s: State, program: &[u64], max_outputs: usize) -> Vec<State> {
+    s.steps += 1;
+    if s.ip + 2 > program.len() || s.steps > MAX_STEPS {
+        return vec![];
+    }
+
+    let Some(opcode) = Opcode::decode(program[s.ip]) else {
+        return vec![];
+    };
+    let operand = program[s.ip + 1];
+    let value = if opcode.takes_combo() {
+        match combo(&s, operand) {
+            Some(value) => value,
+            None => return vec![],
+        }
+    } else {
+        constant(operand)
+    };
+    s.ip += 2;
+
+    match opcode {
+        Opcode::ADV => s.a = circuit.shift_right(&s.a, &value),
+        Opcode::BDV => s.b = circuit.shift_right(&s.a, &value),
+        Opcode::CDV => s.c = circuit.shift_right(&s.a, &value),
+        Opcode::BXL => s.b = circuit.xor_words(&s.b, &value),
+        Opcode::BST => s.b = low3(&value),
+        Opcode::BXC => s.b = circuit.xor_words(&s.b, &s.c),
+        Opcode::OUT => {
+            if s.path.outputs.len() == max_outputs {
+                return vec![];
+            }
+            s.path.outputs.push([value[0], value[1], value[2]]);
+        }
+        Opcode::JNZ => {
+            let nonzero = circuit.any(&s.a);
+            let mut jumped = s.clone();
+            jumped.ip = operand as usize;
+            jumped.path.conditions.push(nonzero);
+            s.path.conditions.push(!nonzero);
+
+            return [jumped, s]
+                .into_iter()
+                .filter(|s| !s.path.conditions.contains(&Bit::Const(false)))
+                .collect();
+        }
+    }
+
+    vec![s]
+}
+
+// Run the program with A unknown, following every path that halts within
+// max_outputs outputs
+pub fn execute_symbolic(computer: &Computer, program: &[u64], max_outputs: usize) -> SymbolicRun {
+    let mut circuit = Circuit::default();
+    let a: Vec<Lit> = (0..WIDTH).map(|_| circuit.cnf.new_var()).collect();
+
+    let mut pending = vec![State {
+        a: a.iter().map(|&lit| Bit::Var(lit)).collect(),
+        b: constant(computer.reg_b),
+        c: constant(computer.reg_c),
+        ip: computer.ip,
+        steps: 0,
+        path: Path {
+            outputs: vec![],
+            conditions: vec![],
+        },
+    }];
+    let mut paths = vec![];
+
+    while let Some(state) = pending.pop() {
+        if state.ip >= program.len() {
+            paths.push(state.path);
+            continue;
+        }
+        pending.extend(step(&mut circuit, state, program, max_outputs));
+    }
+
+    SymbolicRun { circuit, a, paths }
+}
+
+impl SymbolicRun {
+    // The literals that must all hold for path to output exactly target, or
+    // None if it can't
+    fn assumptions(path: &Path, target: &[u64]) -> Option<Vec<Lit>> {
+        if path.outputs.len() != target.len() {
+            return None;
+        }
+
+        let wanted = target.iter().zip(&path.outputs).flat_map(|(&t, bits)| {
+            bits.iter()
+                .enumerate()
+                .map(move |(i, &bit)| if t >> i & 1 == 1 { bit } else { !bit })
+        });
+
+        let mut lits = vec![];
+        for bit in path.conditions.iter().copied().chain(wanted) {
+            match bit {
+                Bit::Const(true) => {}
+                Bit::Const(false) => return None,
+                Bit::Var(lit) => lits.push(lit),
+            }
+        }
+
+        Some(lits)
+    }
+
+    // The smallest A taking path and outputting target, fixing A's bits from
+    // the top down to the smallest value that still has a solution
+    fn smallest_on_path(&self, path: &Path, target: &[u64]) -> Option<u64> {
+        let cnf = &self.circuit.cnf;
+        let order: Vec<usize> = self.a.iter().map(|lit| lit.var()).collect();
+
+        let mut fixed = Self::assumptions(path, target)?;
+        let mut model = sat::solve(cnf, &order, &fixed)?;
+
+        for &bit in self.a.iter().rev() {
+            if !model[bit.var()] {
+                // Already clear in a solution meeting everything fixed so far
+                fixed.push(!bit);
+                continue;
+            }
+
+            fixed.push(!bit);
+            match sat::solve(cnf, &order, &fixed) {
+                Some(clear) => model = clear,
+                None => *fixed.last_mut().unwrap() = bit,
+            }
+        }
+
+        Some(
+            self.a
+                .iter()
+                .enumerate()
+                .map(|(i, lit)| (model[lit.var()] as u64) << i)
+                .sum(),
+        )
+    }
+
+    // The smallest A for which the program outputs exactly target
+    pub fn smallest_a(&self, target: &[u64]) -> Option<u64> {
+        self.paths
+            .iter()
+            .filter_map(|path| self.smallest_on_path(path, target))
+            .min()
+    }
+}
+
+// The smallest value of A for which the program outputs exactly target,
+// for any program whose paths can be explored
+pub fn smallest_a(computer: &Computer, program: &[u64], target: &[u64]) -> Result<u64, QuineError> {
+    if target.iter().any(|&n| n > 7) {
+        return Err(QuineError::NoSolution);
+    }
+
+    execute_symbolic(computer, program, target.len())
+        .smallest_a(target)
+        .ok_or(QuineError::NoSolution)
+}
@@ -0,0 +1,410 @@
+// The day 17 computer and its debugger
+use aoc_testing::Lcg;
+use day17_vm::asm::{asm, asm_with, disasm, disasm_with, format_program};
+use day17_vm::compile::compile;
+use day17_vm::debugger::Debugger;
+use day17_vm::isa::{Action, Instruction, InstructionSet, OperandKind};
+use day17_vm::quine::{smallest_a, smallest_a_any, solve_quine, QuineError};
+use day17_vm::sat::{self, Cnf};
+use day17_vm::symbolic;
+use day17_vm::{read_program, Computer, Limits, VmError};
+use std::io::Cursor;
+use std::path::PathBuf;
+
+const EXAMPLE: &str = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+
+#[test]
+fn steps_trace_decoded_operands() {
+    let (mut computer, program) = read_program(Cursor::new(EXAMPLE)).unwrap();
+
+    let adv = computer.step(&program).unwrap().unwrap();
+    assert_eq!(adv.to_string(), "  0: adv 1");
+    let out = computer.step(&program).unwrap().unwrap();
+    assert_eq!(out.to_string(), "  2: out 4 (a = 364) -> 4");
+    assert_eq!(out.output, Some(4));
+
+    assert_eq!(computer.run(&program), Ok(vec![6, 3, 5, 6, 3, 5, 2, 1, 0]));
+    assert_eq!(computer.step(&program), Ok(None));
+}
+
+#[test]
+fn debugger_stops_at_breakpoints() {
+    let (computer, program) = read_program(Cursor::new(EXAMPLE)).unwrap();
+    let mut debugger = Debugger::new(computer, program);
+
+    let commands = "break 4\ncontinue\ncontinue\nprint regs\nprint out\nquit\nstep\n";
+    let mut out = vec![];
+    debugger.repl(Cursor::new(commands), &mut out).unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.matches("breakpoint at 4").count(), 2);
+    assert!(out.contains("a = 182, b = 0, c = 0, ip = 4"), "{}", out);
+    assert!(out.contains("(vm) 4,6\n"), "{}", out);
+
+    // quit stops reading commands, so the final step never ran
+    assert_eq!(debugger.computer().ip, 4);
+    assert_eq!(debugger.output(), &[4, 6]);
+}
+
+#[test]
+fn bad_programs_are_errors_not_panics() {
+    let run = |program: &[u64]| Computer::new(10, 0, 0).run(program);
+
+    assert_eq!(run(&[5, 4, 0]), Err(VmError::MissingOperand { ip: 2 }));
+    assert_eq!(
+        run(&[5, 4, 8, 0]),
+        Err(VmError::InvalidOpcode { ip: 2, opcode: 8 })
+    );
+    assert_eq!(
+        run(&[5, 7]),
+        Err(VmError::InvalidComboOperand { ip: 0, operand: 7 })
+    );
+
+    // A failed step leaves the computer where it was
+    let mut computer = Computer::new(10, 0, 0);
+    assert!(computer.step(&[2, 7]).is_err());
+    assert_eq!(computer, Computer::new(10, 0, 0));
+
+    // Shifting by 64 or more clears the register rather than overflowing
+    assert_eq!(Computer::new(10, 64, 0).run(&[0, 5, 5, 4]), Ok(vec![0]));
+}
+
+#[test]
+fn runaway_programs_are_stopped() {
+    // jnz back to the start forever, with nothing changing
+    let mut computer = Computer::new(1, 0, 0);
+    let Err(VmError::Loop { state, .. }) = computer.run(&[1, 3, 3, 0]) else {
+        panic!("the loop wasn't detected");
+    };
+    assert_eq!(state.ip, 0);
+
+    // Without loop detection only the step budget stops it
+    let budget = Limits {
+        max_steps: Some(1000),
+        detect_loops: false,
+    };
+    let mut computer = Computer::new(1, 0, 0);
+    assert_eq!(
+        computer.run_with(&[1, 3, 3, 0], budget),
+        Err(VmError::StepLimit { steps: 1000 })
+    );
+
+    // A program finishing right on the budget is fine
+    let mut computer = Computer::new(0, 0, 0);
+    let exact = Limits {
+        max_steps: Some(2),
+        detect_loops: true,
+    };
+    assert_eq!(computer.run_with(&[5, 3, 3, 0], exact), Ok(vec![3]));
+
+    // A budget of no steps at all stops anything that hasn't already halted
+    let none = Limits {
+        max_steps: Some(0),
+        detect_loops: false,
+    };
+    assert_eq!(
+        Computer::new(1, 0, 0).run_with(&[1, 3, 3, 0], none),
+        Err(VmError::StepLimit { steps: 0 })
+    );
+    assert_eq!(Computer::new(1, 0, 0).run_with(&[], none), Ok(vec![]));
+}
+
+#[test]
+fn registers_display_with_ip() {
+    let computer = Computer::new(1, 2, 3);
+    assert_eq!(computer.to_string(), "a = 1, b = 2, c = 3, ip = 0");
+}
+
+#[test]
+fn disasm_round_trips_through_asm() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../examples/day17/example1.txt");
+    let text = std::fs::read_to_string(path).unwrap();
+    let (computer, program) = read_program(Cursor::new(&text)).unwrap();
+
+    let source = disasm(&program);
+    assert_eq!(source, "adv 1\nout a\njnz 0\n");
+    assert_eq!(format_program(&computer, &asm(&source).unwrap()), text);
+
+    // Things that aren't instructions survive too
+    let odd = vec![2, 4, 9, 1, 5];
+    assert_eq!(disasm(&odd), "bst a\nraw 9\nraw 1\nraw 5\n");
+    assert_eq!(asm(&disasm(&odd)).unwrap(), odd);
+}
+
+#[test]
+fn asm_reports_every_bad_line() {
+    let source = "bst a ; comments are ignored\nbxc\nbst d\nout\njmp 0\n";
+    let errors = asm(source).unwrap_err().into_errors();
+    assert_eq!(errors.len(), 3);
+
+    assert_eq!(asm("bst a\nbxc\n").unwrap(), vec![2, 4, 4, 0]);
+}
+
+#[test]
+fn quine_search_finds_the_smallest_a() {
+    let (computer, program) = read_program(Cursor::new(EXAMPLE)).unwrap();
+    let quine = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+    let (quine_computer, quine_program) = read_program(Cursor::new(quine)).unwrap();
+
+    assert_eq!(solve_quine(&quine_computer, &quine_program), Ok(117440));
+    assert_eq!(
+        smallest_a(&quine_computer, &quine_program, &[5, 7, 3, 0]),
+        Ok(2024)
+    );
+
+    // Any target, not just the program itself
+    let a = smallest_a(&computer, &program, &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0]).unwrap();
+    assert!(a <= 729);
+    let mut check = computer.clone();
+    check.reg_a = a;
+    assert_eq!(check.run(&program), Ok(vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]));
+}
+
+#[test]
+fn quine_search_rejects_unsupported_programs() {
+    let computer = Computer::new(0, 0, 0);
+
+    // B is read before anything sets it
+    let carried = smallest_a(&computer, &[0, 3, 1, 1, 5, 5, 3, 0], &[1]);
+    assert!(matches!(carried, Err(QuineError::Unsupported(_))));
+    // Shifting by a register
+    let shifted = smallest_a(&computer, &[0, 4, 5, 4, 3, 0], &[1]);
+    assert!(matches!(shifted, Err(QuineError::Unsupported(_))));
+    // No loop at all
+    let straight = smallest_a(&computer, &[0, 3, 5, 4], &[1]);
+    assert!(matches!(straight, Err(QuineError::Unsupported(_))));
+
+    // Output values only go up to 7
+    let quine_program = [0, 3, 5, 4, 3, 0];
+    assert_eq!(
+        smallest_a(&computer, &quine_program, &[8]),
+        Err(QuineError::NoSolution)
+    );
+}
+
+#[test]
+fn sat_solver_finds_models_and_refutes() {
+    let mut cnf = Cnf::new();
+    let (x, y, z) = (cnf.new_var(), cnf.new_var(), cnf.new_var());
+    cnf.add_clause(vec![x, y]);
+    cnf.add_clause(vec![!x, z]);
+    cnf.add_clause(vec![!y, z]);
+
+    let model = sat::solve(&cnf, &[], &[]).unwrap();
+    assert!(model[x.var()] || model[y.var()]);
+    assert!(model[z.var()]);
+
+    assert_eq!(sat::solve(&cnf, &[], &[!z]), None);
+    let model = sat::solve(&cnf, &[], &[!x]).unwrap();
+    assert!(model[y.var()]);
+}
+
+#[test]
+fn symbolic_execution_matches_the_quine_search() {
+    let quine = "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n";
+    let (computer, program) = read_program(Cursor::new(quine)).unwrap();
+
+    assert_eq!(
+        symbolic::smallest_a(&computer, &program, &program),
+        Ok(117440)
+    );
+    assert_eq!(
+        symbolic::smallest_a(&computer, &program, &[5, 7, 3, 0]),
+        Ok(2024)
+    );
+    assert_eq!(
+        symbolic::smallest_a(&computer, &program, &[8]),
+        Err(QuineError::NoSolution)
+    );
+}
+
+#[test]
+fn unsupported_programs_fall_back_to_symbolic_execution() {
+    let computer = Computer::new(0, 0, 0);
+
+    // B carries over between passes, flipping each time
+    let carried = [0, 3, 1, 1, 5, 5, 3, 0];
+    assert_eq!(smallest_a_any(&computer, &carried, &[1, 0, 1]), Ok(64));
+    assert_eq!(
+        smallest_a_any(&computer, &carried, &[1, 1]),
+        Err(QuineError::NoSolution)
+    );
+
+    // Shifting A by a register, with a jump into the middle of the program
+    let program = [2, 4, 5, 5, 0, 5, 3, 2];
+    assert_eq!(smallest_a_any(&computer, &program, &[3, 3, 3]), Ok(67));
+    let mut check = computer.clone();
+    check.reg_a = 67;
+    assert_eq!(check.run(&program), Ok(vec![3, 3, 3]));
+}
+
+// The stock instructions plus mul, mod, jz, push, pop and hlt, with combo
+// operand 7 reading an extra register
+fn extended() -> InstructionSet {
+    let mut isa = InstructionSet::stock();
+    isa.insert(
+        8,
+        Instruction::new("mul", OperandKind::Combo, |c, v| {
+            c.reg_b = c.reg_b.wrapping_mul(v);
+            Action::Next
+        }),
+    );
+    isa.insert(
+        9,
+        Instruction::new("mod", OperandKind::Literal, |c, v| {
+            if v == 0 {
+                return Action::Fault("mod by zero");
+            }
+            c.reg_b %= v;
+            Action::Next
+        }),
+    );
+    isa.insert(
+        10,
+        Instruction::new("jz", OperandKind::Literal, |c, v| {
+            if c.reg_a == 0 {
+                Action::Jump(v as usize)
+            } else {
+                Action::Next
+            }
+        }),
+    );
+    isa.insert(
+        11,
+        Instruction::new("push", OperandKind::Combo, |c, v| {
+            c.stack.push(v);
+            Action::Next
+        }),
+    );
+    isa.insert(
+        12,
+        Instruction::new("pop", OperandKind::Literal, |c, _| {
+            let Some(value) = c.stack.pop() else {
+                return Action::Fault("pop from an empty stack");
+            };
+            c.reg_b = value;
+            Action::Next
+        }),
+    );
+    isa.insert(
+        13,
+        Instruction::new("hlt", OperandKind::Literal, |_, _| Action::Halt),
+    );
+    isa.set_combo_decoder(|c, operand| match operand {
+        7 => c.extra.first().copied(),
+        _ => day17_vm::decode_combo_operand(c, operand),
+    });
+
+    isa
+}
+
+#[test]
+fn extended_instruction_sets_plug_into_the_vm() {
+    let isa = extended();
+    let source = "push a\nbst 7\nmul a\nout b\npop 0\nmod 2\nout b\njz 0\n";
+    let program = asm_with(&isa, source).unwrap();
+    assert_eq!(
+        program,
+        vec![11, 4, 2, 7, 8, 4, 5, 5, 12, 0, 9, 2, 5, 5, 10, 0]
+    );
+    assert_eq!(disasm_with(&isa, &program), source);
+
+    let mut computer = Computer::new(3, 0, 0);
+    computer.extra = vec![5];
+    let mut first = computer.clone();
+    assert_eq!(
+        isa.step(&mut first, &program).unwrap().unwrap().to_string(),
+        "  0: push 4 (a = 3)"
+    );
+    assert_eq!(first.stack, vec![3]);
+    assert_eq!(
+        isa.run(&mut computer.clone(), &program, Limits::default()),
+        Ok(vec![7, 1])
+    );
+
+    // The stock set doesn't know the new opcodes
+    assert_eq!(
+        computer.run(&program),
+        Err(VmError::InvalidOpcode { ip: 0, opcode: 11 })
+    );
+    assert!(asm("push a\n").is_err());
+
+    let mut out = vec![];
+    let mut debugger = Debugger::new(computer, program).with_instruction_set(isa);
+    debugger.command("step 3", &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("  2: bst 7 (= 5)"), "{}", out);
+    assert_eq!(debugger.computer().reg_b, 15);
+}
+
+#[test]
+fn extended_instructions_can_fault_or_halt() {
+    let isa = extended();
+    let run = |source: &str| {
+        let program = asm_with(&isa, source).unwrap();
+        isa.run(&mut Computer::new(3, 0, 0), &program, Limits::default())
+    };
+
+    assert_eq!(
+        run("out 4\npop 0\nout 4\n"),
+        Err(VmError::Fault {
+            ip: 2,
+            reason: "pop from an empty stack"
+        })
+    );
+    assert_eq!(
+        run("mod 0\n"),
+        Err(VmError::Fault {
+            ip: 0,
+            reason: "mod by zero"
+        })
+    );
+    assert_eq!(run("out 4\nhlt 0\nout 4\n"), Ok(vec![3]));
+
+    // A fault leaves the computer as it was
+    let program = asm_with(&isa, "pop 0\n").unwrap();
+    let mut computer = Computer::new(3, 1, 0);
+    assert!(isa.step(&mut computer, &program).is_err());
+    assert_eq!(computer, Computer::new(3, 1, 0));
+}
+
+#[test]
+fn compiled_programs_match_the_interpreter() {
+    // Random programs from a fixed LCG, many of them broken or looping
+    let mut rng = Lcg::new(17);
+
+    for _ in 0..500 {
+        let len = rng.below(12) as usize + 1;
+        let program: Vec<u64> = (0..len).map(|_| rng.below(8)).collect();
+        let computer = Computer::new(rng.below(1 << 20), rng.below(8), rng.below(8));
+
+        // Compiled code has no loop detection, only the step budget
+        let expected = computer.clone().run(&program);
+        if let Err(VmError::Loop { .. } | VmError::StepLimit { .. }) = expected {
+            continue;
+        }
+        assert_eq!(
+            compile(&program).run(&computer, usize::MAX),
+            expected,
+            "{:?}",
+            program
+        );
+    }
+}
+
+#[test]
+fn compiled_programs_stop_early() {
+    let (computer, program) = read_program(Cursor::new(EXAMPLE)).unwrap();
+    let compiled = compile(&program);
+
+    assert_eq!(compiled.run(&computer, 3), Ok(vec![4, 6, 3]));
+    assert_eq!(compiled.run(&computer, 0), Ok(vec![]));
+
+    let full = [4, 6, 3, 5, 6, 3, 5, 2, 1, 0];
+    assert!(compiled.outputs_exactly(&computer, 729, &full));
+    assert!(!compiled.outputs_exactly(&computer, 729, &full[..9]));
+    assert!(!compiled.outputs_exactly(&computer, 729, &[4, 6, 3, 5, 6, 3, 5, 2, 1, 0, 0]));
+    // adv 1 then out a, so each output is a bit further up A
+    assert_eq!(compiled.find_a(&computer, 0..1000, &[2, 1, 0]), Some(4));
+    assert_eq!(compiled.find_a(&computer, 0..1000, &[4, 6]), None);
+}